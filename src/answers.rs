@@ -0,0 +1,127 @@
+use anyhow::{bail, Context, Result};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::Part;
+
+/// Expected answers, keyed by day and part.
+///
+/// They are stored as TOML with a table per day:
+///
+/// ```toml
+/// [day01]
+/// part1 = "928896"
+/// part2 = "295668576"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, Part), String>,
+}
+
+impl Answers {
+    /// Loads the answers from `path`. A missing file has no answers.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+
+        match fs::read_to_string(path) {
+            Ok(s) => Answers::parse(&s)
+                .with_context(|| format!("Invalid '{}'", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                Ok(Answers::default())
+            }
+            Err(e) => Err(e).with_context(|| {
+                format!("Failed to read '{}'", path.display())
+            }),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self> {
+        let table: toml::Table = s.parse()?;
+        let mut answers = Answers::default();
+
+        for (key, parts) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .with_context(|| format!("Invalid day '{}'", key))?;
+            let parts = parts
+                .as_table()
+                .with_context(|| format!("'{}' is not a table", key))?;
+
+            for (key, answer) in parts {
+                let part = match key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => bail!("Invalid part '{}' of day {}", key, day),
+                };
+                let answer = match answer {
+                    toml::Value::String(s) => s.clone(),
+                    toml::Value::Integer(n) => n.to_string(),
+                    _ => bail!("Invalid answer of day {} part {}", day, part),
+                };
+
+                answers.set(day, part, answer);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Failed to write '{}'", path.display()))
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: u32, part: Part, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut table = toml::Table::new();
+
+        for ((day, part), answer) in &self.answers {
+            let parts = table
+                .entry(format!("day{:02}", day))
+                .or_insert_with(|| toml::Table::new().into());
+
+            if let toml::Value::Table(parts) = parts {
+                parts.insert(format!("part{}", part), answer.clone().into());
+            }
+        }
+
+        write!(f, "{}", table)
+    }
+}
+
+#[test]
+fn test_roundtrip() {
+    let mut answers = Answers::default();
+    answers.set(10, Part::Two, "3454189699072".to_owned());
+    answers.set(1, Part::One, "928896".to_owned());
+    answers.set(1, Part::Two, "295668576".to_owned());
+
+    let s = answers.to_string();
+    assert!(s.starts_with("[day01]\npart1 = \"928896\"\n"));
+    assert_eq!(Answers::parse(&s).unwrap(), answers);
+
+    let answers = Answers::parse("[day07]\npart1 = 337\n").unwrap();
+    assert_eq!(answers.get(7, Part::One), Some("337"));
+    assert_eq!(answers.get(7, Part::Two), None);
+
+    assert!(Answers::parse("[day07]\npart3 = 1\n").is_err());
+}
@@ -0,0 +1,171 @@
+use anyhow::{bail, Context, Result};
+use std::fmt::Write as _;
+use std::fmt::{self, Display};
+use std::iter;
+use std::time::{Duration, Instant};
+
+use crate::{Day, Part, Puzzle};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part(Part::One) => f.pad("part1"),
+            Stage::Part(Part::Two) => f.pad("part2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        let total: Duration = samples.iter().sum();
+
+        Some(Stats {
+            runs: samples.len(),
+            min: samples[0],
+            median,
+            mean: total / samples.len() as u32,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bench {
+    pub day: u32,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Times `runs` parses of the `input` text and `runs` solves of every part. A part
+/// that fails to solve does not prevent the others from being timed.
+pub fn bench(
+    day: &Day,
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+    strict: bool,
+) -> Vec<Result<Bench>> {
+    match bench_parse(day, input, runs, strict) {
+        Ok((puzzle, bench)) => iter::once(Ok(bench))
+            .chain(parts.iter().map(|&part| {
+                bench_part(puzzle.as_ref(), part, runs)
+                    .map(|stats| Bench {
+                        day: day.day,
+                        stage: Stage::Part(part),
+                        stats,
+                    })
+                    .with_context(|| {
+                        format!("Failed to solve day {} part {}", day.day, part)
+                    })
+            }))
+            .collect(),
+        Err(e) => {
+            vec![Err(e.context(format!("Failed to parse day {}", day.day)))]
+        }
+    }
+}
+
+fn bench_parse(
+    day: &Day,
+    input: &str,
+    runs: usize,
+    strict: bool,
+) -> Result<(Box<dyn Puzzle>, Bench)> {
+    if runs == 0 {
+        bail!("Number of runs must be positive");
+    }
+
+    let mut samples = Vec::with_capacity(runs);
+    let mut puzzle = None;
+
+    for _ in 0..runs {
+        let now = Instant::now();
+        puzzle = Some(day.parse_with(input, strict)?);
+        samples.push(now.elapsed());
+    }
+
+    let bench = Bench {
+        day: day.day,
+        stage: Stage::Parse,
+        stats: Stats::new(&samples).unwrap(),
+    };
+
+    Ok((puzzle.unwrap(), bench))
+}
+
+fn bench_part(puzzle: &dyn Puzzle, part: Part, runs: usize) -> Result<Stats> {
+    let mut samples = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let now = Instant::now();
+        puzzle.solve(part)?;
+        samples.push(now.elapsed());
+    }
+
+    Ok(Stats::new(&samples).unwrap())
+}
+
+/// Renders `benches` as tab separated values, with durations in nanoseconds.
+pub fn report(benches: &[Bench]) -> String {
+    let mut s = String::from("day\tstage\truns\tmin_ns\tmedian_ns\tmean_ns\n");
+
+    for bench in benches {
+        writeln!(
+            s,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            bench.day,
+            bench.stage,
+            bench.stats.runs,
+            bench.stats.min.as_nanos(),
+            bench.stats.median.as_nanos(),
+            bench.stats.mean.as_nanos()
+        )
+        .unwrap();
+    }
+
+    s
+}
+
+#[test]
+fn test_stats() {
+    let ms = Duration::from_millis;
+
+    let stats = Stats::new(&[ms(5), ms(1), ms(3)]).unwrap();
+    assert_eq!(stats.runs, 3);
+    assert_eq!(stats.min, ms(1));
+    assert_eq!(stats.median, ms(3));
+    assert_eq!(stats.mean, ms(3));
+
+    let stats = Stats::new(&[ms(4), ms(1), ms(2), ms(9)]).unwrap();
+    assert_eq!(stats.median, ms(3));
+    assert_eq!(stats.mean, ms(4));
+
+    assert!(Stats::new(&[]).is_none());
+}
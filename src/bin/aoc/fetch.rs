@@ -0,0 +1,75 @@
+use anyhow::{bail, Context, Result};
+use aoc_2020::client::{Client, Fetched};
+use aoc_2020::input_dir;
+
+pub const USAGE: &str = "\
+Usage: aoc fetch [OPTIONS] DAY...
+
+Downloads the inputs of the given days into $AOC_INPUT_DIR or ./data.
+Inputs that are already there are never downloaded again.
+
+Options:
+        --session TOKEN Session cookie. Defaults to $AOC_SESSION.
+        --base-url URL  Website to download from. Defaults to $AOC_BASE_URL
+                        or https://adventofcode.com.
+    -h, --help          Print this help";
+
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut days = Vec::new();
+    let mut session = None;
+    let mut base_url = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            "--session" => {
+                session = Some(args.next().context("Missing session token")?);
+            }
+            "--base-url" => {
+                base_url = Some(args.next().context("Missing base URL")?);
+            }
+            _ if arg.starts_with('-') => {
+                bail!("Unknown option '{}'\n{}", arg, USAGE)
+            }
+            _ => days.push(arg.parse::<u32>().context("Invalid day")?),
+        }
+    }
+
+    if days.is_empty() {
+        bail!("Missing DAY\n{}", USAGE);
+    }
+
+    let dir = input_dir();
+    let mut client = None;
+
+    for day in days {
+        let path = dir.join(format!("input{:02}.txt", day));
+
+        // Do not require a session when everything is cached.
+        if path.exists() {
+            println!("Day {}: cached at '{}'", day, path.display());
+            continue;
+        }
+
+        if client.is_none() {
+            client = Some(Client::from_env(
+                base_url.as_deref(),
+                session.as_deref(),
+            )?);
+        }
+
+        match client.as_ref().unwrap().fetch_to_dir(day, &dir)? {
+            Fetched::Cached(path) => {
+                println!("Day {}: cached at '{}'", day, path.display())
+            }
+            Fetched::Downloaded(path) => {
+                println!("Day {}: downloaded to '{}'", day, path.display())
+            }
+        }
+    }
+
+    Ok(())
+}
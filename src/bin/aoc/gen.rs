@@ -0,0 +1,58 @@
+use anyhow::{bail, Context, Result};
+use aoc_2020::gen::{self, Rng};
+use aoc_2020::submit;
+
+pub const USAGE: &str = "\
+Usage: aoc gen [OPTIONS] DAY
+
+Prints a random, valid input for DAY. The seed is printed to stderr, so
+that the same input can be generated again.
+
+Options:
+        --size N        Roughly the number of lines or items. Defaults
+                        to 100.
+        --seed SEED     Seed of the input. Defaults to the current time.
+    -h, --help          Print this help";
+
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut day = None;
+    let mut size = 100;
+    let mut seed = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            "--size" => {
+                size = args
+                    .next()
+                    .context("Missing size")?
+                    .parse()
+                    .context("Invalid size")?;
+            }
+            "--seed" => {
+                seed = Some(
+                    args.next()
+                        .context("Missing seed")?
+                        .parse()
+                        .context("Invalid seed")?,
+                );
+            }
+            _ if arg.starts_with('-') => {
+                bail!("Unknown option '{}'\n{}", arg, USAGE)
+            }
+            _ if day.is_some() => bail!("Too many arguments\n{}", USAGE),
+            _ => day = Some(arg.parse::<u32>().context("Invalid day")?),
+        }
+    }
+
+    let day = day.with_context(|| format!("Missing DAY\n{}", USAGE))?;
+    let seed = seed.unwrap_or_else(submit::unix_time);
+
+    eprintln!("Day {} seed {} size {}", day, seed, size);
+    print!("{}", gen::generate(day, &mut Rng::new(seed), size)?);
+
+    Ok(())
+}
@@ -0,0 +1,450 @@
+use anyhow::{bail, Context, Result};
+use aoc_2020::bench::{self, Bench};
+use aoc_2020::memory::{self, CountingAlloc};
+use aoc_2020::output::{self, Format, Record};
+use aoc_2020::parallel::run_parallel;
+use aoc_2020::visualize::{self, Visualizer};
+use aoc_2020::{find_day, input_dir, Answers, Day, InputSource, Part, DAYS};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+mod fetch;
+mod gen;
+mod new_day;
+mod submit;
+mod watch;
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+const USAGE: &str = "\
+Usage: aoc [OPTIONS] [DAY [PART]]
+       aoc fetch [OPTIONS] DAY...
+       aoc gen [OPTIONS] DAY
+       aoc new-day [OPTIONS] DAY
+       aoc submit [OPTIONS] DAY PART [ANSWER]
+       aoc watch [OPTIONS] DAY
+
+Options:
+    -i, --input PATH    Read the input from PATH, or stdin if PATH is '-'.
+                        Requires a DAY. Defaults to $AOC_INPUT_DIR/inputNN.txt
+                        or ./data/inputNN.txt.
+        --check         Compare the answers with the recorded ones and fail
+                        on any mismatch.
+        --record        Record the answers.
+        --answers PATH  Recorded answers file. Defaults to answers.toml next
+                        to the inputs.
+        --bench RUNS    Time RUNS parses and solves of each day and print
+                        their min/median/mean.
+        --report PATH   With --bench, also write the timings to PATH as
+                        tab separated values.
+        --format FORMAT Print the answers as text, json or csv. The json and
+                        csv records have the day, part, answer, duration_ns
+                        and error fields.
+        --strict        Fail on malformed input lines instead of skipping
+                        them.
+    -j, --jobs N        Solve up to N days and parts at once, on as many
+                        threads. Defaults to 1.
+        --memory        Also print the peak heap usage, number of
+                        allocations and bytes allocated of each part.
+        --visualize     Render every step of solving DAY, for days 11 and
+                        12.
+        --delay MS      With --visualize, wait MS milliseconds between
+                        steps. Defaults to 100.
+        --frames PATH   With --visualize, write the steps to PATH instead of
+                        animating them.
+    -h, --help          Print this help";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Run,
+    Check,
+    Record,
+    Bench(usize),
+    Visualize,
+}
+
+struct Args {
+    days: Vec<&'static Day>,
+    parts: Vec<Part>,
+    input: Option<String>,
+    mode: Mode,
+    answers: PathBuf,
+    report: Option<PathBuf>,
+    format: Format,
+    strict: bool,
+    jobs: usize,
+    memory: bool,
+    delay: Duration,
+    frames: Option<PathBuf>,
+}
+
+/// Sets the mode of the run, failing if another mode flag was already given.
+fn set_mode(mode: &mut Mode, new: Mode) -> Result<()> {
+    if *mode != Mode::Run {
+        bail!(
+            "Only one of --check, --record, --bench and --visualize can be \
+             used"
+        );
+    }
+
+    *mode = new;
+    Ok(())
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args> {
+    let mut positional = Vec::new();
+    let mut input = None;
+    let mut mode = Mode::Run;
+    let mut answers = None;
+    let mut report = None;
+    let mut format = Format::Text;
+    let mut strict = false;
+    let mut jobs = 1;
+    let mut memory = false;
+    let mut delay = Duration::from_millis(100);
+    let mut frames = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            "-i" | "--input" => {
+                input = Some(args.next().context("Missing input path")?);
+            }
+            "--check" => set_mode(&mut mode, Mode::Check)?,
+            "--record" => set_mode(&mut mode, Mode::Record)?,
+            "--answers" => {
+                answers = Some(args.next().context("Missing answers path")?);
+            }
+            "--bench" => {
+                let runs = args.next().context("Missing number of runs")?;
+                let runs = runs.parse().context("Invalid runs")?;
+
+                if runs == 0 {
+                    bail!("--bench requires at least one run");
+                }
+
+                set_mode(&mut mode, Mode::Bench(runs))?;
+            }
+            "--report" => {
+                report = Some(args.next().context("Missing report path")?);
+            }
+            "--format" => {
+                format = args.next().context("Missing format")?.parse()?;
+            }
+            "--strict" => strict = true,
+            "-j" | "--jobs" => {
+                let n = args.next().context("Missing number of jobs")?;
+                jobs = n.parse().context("Invalid number of jobs")?;
+
+                if jobs == 0 {
+                    bail!("--jobs requires at least one job");
+                }
+            }
+            "--memory" => memory = true,
+            "--visualize" => set_mode(&mut mode, Mode::Visualize)?,
+            "--delay" => {
+                let ms = args.next().context("Missing delay")?;
+                delay =
+                    Duration::from_millis(ms.parse().context("Invalid delay")?);
+            }
+            "--frames" => {
+                frames = Some(args.next().context("Missing frames path")?);
+            }
+            "-" => positional.push(arg),
+            _ if arg.starts_with('-') => {
+                bail!("Unknown option '{}'\n{}", arg, USAGE)
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    if positional.len() > 2 {
+        bail!("Too many arguments\n{}", USAGE);
+    }
+
+    let days = match positional.first() {
+        Some(day) => {
+            let day = day.parse().context("Invalid day")?;
+            let day = find_day(day)
+                .with_context(|| format!("Day {} is not solved", day))?;
+            vec![day]
+        }
+        None if input.is_some() => bail!("--input requires a DAY"),
+        None => DAYS.iter().collect(),
+    };
+
+    let parts = match positional.get(1) {
+        Some(part) => vec![part.parse()?],
+        None => Part::BOTH.to_vec(),
+    };
+
+    if format != Format::Text
+        && (mode == Mode::Check || matches!(mode, Mode::Bench(_)))
+    {
+        bail!("--format can not be used with --check or --bench");
+    }
+
+    if mode == Mode::Visualize {
+        match positional.first() {
+            Some(_) if !visualize::DAYS.contains(&days[0].day) => {
+                bail!("Day {} has no visualization", days[0].day)
+            }
+            Some(_) => {}
+            None => bail!("--visualize requires a DAY"),
+        }
+
+        if format != Format::Text || memory || jobs > 1 {
+            bail!(
+                "--visualize can not be used with --format, --memory or \
+                 --jobs"
+            );
+        }
+    } else if frames.is_some() {
+        bail!("--frames requires --visualize");
+    }
+
+    if jobs > 1 && matches!(mode, Mode::Bench(_)) {
+        bail!("--jobs can not be used with --bench");
+    }
+
+    if memory && (mode == Mode::Check || matches!(mode, Mode::Bench(_))) {
+        bail!("--memory can not be used with --check or --bench");
+    }
+
+    let answers = answers
+        .map(PathBuf::from)
+        .unwrap_or_else(|| input_dir().join("answers.toml"));
+
+    Ok(Args {
+        days,
+        parts,
+        input,
+        mode,
+        answers,
+        report: report.map(PathBuf::from),
+        format,
+        strict,
+        jobs,
+        memory,
+        delay,
+        frames: frames.map(PathBuf::from),
+    })
+}
+
+/// Prints how `records` compare to the `expected` answers and returns the
+/// number of failures. Parts without a recorded answer never fail.
+fn check(records: &[Record], expected: &Answers) -> usize {
+    let mut failures = 0;
+    let width = records
+        .iter()
+        .filter_map(|rec| expected.get(rec.day, rec.part))
+        .map(str::len)
+        .fold("Expected".len(), usize::max);
+
+    println!("Day  Part  Status      {:<width$}  Actual", "Expected");
+
+    for rec in records {
+        let expected_answer = expected.get(rec.day, rec.part);
+        let (status, actual) = match (&rec.answer, expected_answer) {
+            (Ok(answer), Some(exp)) if answer == exp => ("ok", answer.clone()),
+            (Ok(answer), Some(_)) => ("MISMATCH", answer.clone()),
+            (Ok(answer), None) => ("unrecorded", answer.clone()),
+            (Err(e), Some(_)) => ("ERROR", format!("{:#}", e)),
+            (Err(e), None) => ("unrecorded", format!("error: {:#}", e)),
+        };
+
+        if status == "MISMATCH" || status == "ERROR" {
+            failures += 1;
+        }
+
+        println!(
+            "{:>3}  {:>4}  {:<10}  {:<width$}  {}",
+            rec.day,
+            rec.part,
+            status,
+            expected_answer.unwrap_or("-"),
+            actual
+        );
+    }
+
+    failures
+}
+
+fn record(records: &[Record], answers: &mut Answers) -> usize {
+    let mut recorded = 0;
+
+    for rec in records {
+        if let Ok(answer) = &rec.answer {
+            answers.set(rec.day, rec.part, answer.clone());
+            recorded += 1;
+        }
+    }
+
+    recorded
+}
+
+fn print_benches(benches: &[Bench]) {
+    println!("Day  Stage        Min      Median        Mean  Runs");
+
+    for bench in benches {
+        println!(
+            "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>4}",
+            bench.day,
+            bench.stage,
+            format!("{:.1?}", bench.stats.min),
+            format!("{:.1?}", bench.stats.median),
+            format!("{:.1?}", bench.stats.mean),
+            bench.stats.runs
+        );
+    }
+}
+
+fn run_visualize(args: &Args) -> Result<()> {
+    let day = args.days[0].day;
+    let input = InputSource::resolve(day, args.input.as_deref()).read()?;
+    let mut vis = match &args.frames {
+        Some(path) => Visualizer::file(path)?,
+        None => Visualizer::terminal(args.delay),
+    };
+
+    for &part in &args.parts {
+        visualize::visualize(day, &input, part, &mut vis)?;
+    }
+
+    if let Some(path) = &args.frames {
+        println!("Wrote {} frames to '{}'", vis.frames(), path.display());
+    }
+
+    vis.finish()
+}
+
+fn run_benches(args: &Args, runs: usize) -> Result<()> {
+    let mut benches = Vec::new();
+    let mut failures = 0;
+
+    for day in &args.days {
+        let input = InputSource::resolve(day.day, args.input.as_deref());
+        let input = match input.read() {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{:#}", e);
+                failures += 1;
+                continue;
+            }
+        };
+
+        for res in bench::bench(day, &input, &args.parts, runs, args.strict) {
+            match res {
+                Ok(b) => benches.push(b),
+                Err(e) => {
+                    eprintln!("{:#}", e);
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    print_benches(&benches);
+
+    if let Some(path) = &args.report {
+        fs::write(path, bench::report(&benches))
+            .with_context(|| format!("Failed to write '{}'", path.display()))?;
+    }
+
+    if failures > 0 {
+        bail!("{} benchmarks failed", failures);
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1).peekable();
+
+    if args.peek().map(String::as_str) == Some("fetch") {
+        args.next();
+        return fetch::main(args);
+    }
+
+    if args.peek().map(String::as_str) == Some("gen") {
+        args.next();
+        return gen::main(args);
+    }
+
+    if args.peek().map(String::as_str) == Some("new-day") {
+        args.next();
+        return new_day::main(args);
+    }
+
+    if args.peek().map(String::as_str) == Some("submit") {
+        args.next();
+        return submit::main(args);
+    }
+
+    if args.peek().map(String::as_str) == Some("watch") {
+        args.next();
+        return watch::main(args);
+    }
+
+    let args = parse_args(args)?;
+
+    if args.memory {
+        memory::enable();
+    }
+
+    if let Mode::Bench(runs) = args.mode {
+        return run_benches(&args, runs);
+    }
+
+    if args.mode == Mode::Visualize {
+        return run_visualize(&args);
+    }
+
+    let days: Vec<_> = args
+        .days
+        .iter()
+        .map(|&day| (day, InputSource::resolve(day.day, args.input.as_deref())))
+        .collect();
+    let records = run_parallel(&days, &args.parts, args.jobs, args.strict);
+
+    match args.mode {
+        Mode::Run => {
+            print!("{}", output::render(&records, args.format));
+            eprint!("{}", output::diagnostics(&records));
+        }
+        Mode::Check => {
+            let expected = Answers::load(&args.answers)?;
+            let failures = check(&records, &expected);
+            eprint!("{}", output::diagnostics(&records));
+
+            if failures > 0 {
+                bail!(
+                    "{} of {} answers failed the check",
+                    failures,
+                    records.len()
+                );
+            }
+        }
+        Mode::Bench(_) | Mode::Visualize => unreachable!(),
+        Mode::Record => {
+            let mut answers = Answers::load(&args.answers)?;
+            let recorded = record(&records, &mut answers);
+
+            print!("{}", output::render(&records, args.format));
+            answers.save(&args.answers)?;
+            eprintln!(
+                "Recorded {} answers to '{}'",
+                recorded,
+                args.answers.display()
+            );
+        }
+    }
+
+    Ok(())
+}
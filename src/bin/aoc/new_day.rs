@@ -0,0 +1,176 @@
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const USAGE: &str = "\
+Usage: aoc new-day [OPTIONS] DAY
+
+Creates the module, binary and example test skeleton of DAY, and registers
+the module with the library and the dispatched days. Existing days are
+never overwritten.
+
+Options:
+        --root PATH     Crate to add the day to. Defaults to the current
+                        directory.
+    -h, --help          Print this help";
+
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut day = None;
+    let mut root = PathBuf::from(".");
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            "--root" => {
+                root = PathBuf::from(args.next().context("Missing root path")?);
+            }
+            _ if arg.starts_with('-') => {
+                bail!("Unknown option '{}'\n{}", arg, USAGE)
+            }
+            _ if day.is_some() => bail!("Too many arguments\n{}", USAGE),
+            _ => day = Some(arg.parse::<u32>().context("Invalid day")?),
+        }
+    }
+
+    let day = day.with_context(|| format!("Missing DAY\n{}", USAGE))?;
+
+    if !(1..=25).contains(&day) {
+        bail!("Day {} is not between 1 and 25", day);
+    }
+
+    let module = root.join(format!("src/day{:02}.rs", day));
+    let binary = root.join(format!("src/bin/day{:02}.rs", day));
+    let lib = root.join("src/lib.rs");
+    let solution = root.join("src/solution.rs");
+
+    for path in &[&module, &binary] {
+        if path.exists() {
+            bail!("Day {} already exists: '{}'", day, path.display());
+        }
+    }
+
+    // Edit everything in memory first, so that nothing is written unless
+    // every file can be updated.
+    let lib_src =
+        register(&lib, &format!("pub mod day{:02};", day), "pub mod day", day)?;
+    let solution_src = register(
+        &solution,
+        &format!("    Day::new::<day{:02}::Day{:02}>(),", day, day),
+        "    Day::new::<day",
+        day,
+    )?;
+
+    write(&module, &module_template(day))?;
+    write(&binary, &binary_template(day))?;
+    write(&lib, &lib_src)?;
+    write(&solution, &solution_src)?;
+
+    println!("Created day {}:", day);
+    for path in &[&module, &binary] {
+        println!("    {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Inserts `line` among the consecutive lines of `path` that start with
+/// `prefix` followed by a day number, keeping them sorted by day.
+fn register(path: &Path, line: &str, prefix: &str, day: u32) -> Result<String> {
+    let src = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read '{}'", path.display()))?;
+    let mut lines: Vec<&str> = src.lines().collect();
+
+    let days: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, ln)| {
+            let rest = ln.strip_prefix(prefix)?;
+            let digits: String =
+                rest.chars().take_while(char::is_ascii_digit).collect();
+            Some((i, digits.parse().ok()?))
+        })
+        .collect();
+
+    if days.iter().any(|&(_, d)| d == day) {
+        bail!("Day {} is already registered in '{}'", day, path.display());
+    }
+
+    let i = match days.iter().find(|&&(_, d)| d > day) {
+        Some(&(i, _)) => i,
+        None => match days.last() {
+            Some(&(i, _)) => i + 1,
+            None => bail!("No days found in '{}'", path.display()),
+        },
+    };
+
+    lines.insert(i, line);
+
+    let mut src = lines.join("\n");
+    src.push('\n');
+
+    Ok(src)
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents)
+        .with_context(|| format!("Failed to write '{}'", path.display()))
+}
+
+fn module_template(day: u32) -> String {
+    format!(
+        "\
+use anyhow::{{bail, Result}};
+
+use crate::Solution;
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    const DAY: u32 = {day};
+
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        Ok(input.lines().map(str::to_owned).collect())
+    }}
+
+    fn part1(_: &Self::Input) -> Result<u64> {{
+        bail!(\"part1 not solved\");
+    }}
+
+    fn part2(_: &Self::Input) -> Result<u64> {{
+        bail!(\"part2 not solved\");
+    }}
+}}
+
+#[test]
+#[ignore = \"example not filled in yet\"]
+fn test_example() {{
+    let input = Day{day:02}::parse(\"\").unwrap();
+
+    assert_eq!(Day{day:02}::part1(&input).unwrap(), 0);
+    assert_eq!(Day{day:02}::part2(&input).unwrap(), 0);
+}}
+",
+        day = day
+    )
+}
+
+fn binary_template(day: u32) -> String {
+    format!(
+        "\
+use anyhow::Result;
+use aoc_2020::day{day:02}::Day{day:02};
+
+fn main() -> Result<()> {{
+    aoc_2020::run::<Day{day:02}>()
+}}
+",
+        day = day
+    )
+}
@@ -0,0 +1,122 @@
+use anyhow::{bail, Context, Result};
+use aoc_2020::client::Client;
+use aoc_2020::submit::{self, Attempt, History, Verdict};
+use aoc_2020::{find_day, input_dir, InputSource, Part};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: aoc submit [OPTIONS] DAY PART [ANSWER]
+
+Submits ANSWER, or the computed answer, of PART of DAY. Every attempt is
+recorded, and answers that are known to be wrong or out of bounds are
+never submitted again.
+
+Options:
+    -i, --input PATH    Input to compute the answer from.
+        --history PATH  Submission history. Defaults to submissions.tsv next
+                        to the inputs.
+        --session TOKEN Session cookie. Defaults to $AOC_SESSION.
+        --base-url URL  Website to submit to. Defaults to $AOC_BASE_URL
+                        or https://adventofcode.com.
+    -h, --help          Print this help";
+
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut positional = Vec::new();
+    let mut input = None;
+    let mut history = None;
+    let mut session = None;
+    let mut base_url = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            "-i" | "--input" => {
+                input = Some(args.next().context("Missing input path")?);
+            }
+            "--history" => {
+                history = Some(PathBuf::from(
+                    args.next().context("Missing history path")?,
+                ));
+            }
+            "--session" => {
+                session = Some(args.next().context("Missing session token")?);
+            }
+            "--base-url" => {
+                base_url = Some(args.next().context("Missing base URL")?);
+            }
+            _ if arg.starts_with('-') => {
+                bail!("Unknown option '{}'\n{}", arg, USAGE)
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let (day, part, answer) = match positional.as_slice() {
+        [day, part] => (day, part, None),
+        [day, part, answer] => (day, part, Some(answer.clone())),
+        [] | [_] => bail!("Missing DAY or PART\n{}", USAGE),
+        _ => bail!("Too many arguments\n{}", USAGE),
+    };
+    let day: u32 = day.parse().context("Invalid day")?;
+    let part: Part = part.parse()?;
+
+    if !(1..=25).contains(&day) {
+        bail!("Day {} is not between 1 and 25", day);
+    }
+
+    // An answer given on the command line needs no solver.
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solver = find_day(day)
+                .with_context(|| format!("Day {} is not solved", day))?;
+            let input = InputSource::resolve(day, input.as_deref());
+            let record = solver.run(&input, &[part], false).pop().unwrap();
+            let answer = record.answer.with_context(|| {
+                format!("Failed to solve day {} part {}", day, part)
+            })?;
+
+            println!("Day {} part {}: {}", day, part, answer);
+            answer
+        }
+    };
+
+    let history_path =
+        history.unwrap_or_else(|| input_dir().join("submissions.tsv"));
+    let mut history = History::load(&history_path)?;
+    let now = submit::unix_time();
+
+    history
+        .check(day, part, &answer, now)
+        .with_context(|| format!("Refusing to submit '{}'", answer))?;
+
+    let client = Client::from_env(base_url.as_deref(), session.as_deref())?;
+    let verdict = client.submit(day, part, &answer)?;
+
+    history.push(Attempt {
+        time: now,
+        day,
+        part,
+        answer: answer.clone(),
+        verdict,
+    })?;
+
+    match verdict {
+        Verdict::Correct => println!("'{}' is correct", answer),
+        Verdict::Wrong => bail!("'{}' is wrong", answer),
+        Verdict::TooHigh => bail!("'{}' is too high", answer),
+        Verdict::TooLow => bail!("'{}' is too low", answer),
+        Verdict::Wait(d) => {
+            bail!("Answered too recently, wait {}s", d.as_secs())
+        }
+        Verdict::WrongLevel => {
+            bail!("Day {} part {} is already solved or locked", day, part)
+        }
+        Verdict::Unknown => bail!("Unrecognized response"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,291 @@
+use anyhow::{bail, Context, Result};
+use aoc_2020::{find_day, InputSource, Part};
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+pub const USAGE: &str = "\
+Usage: aoc watch [OPTIONS] DAY
+
+Solves DAY, then solves it again whenever its module or its input changes,
+printing the previous and new answers side by side. The solutions are
+rebuilt with cargo before each run.
+
+Options:
+    -i, --input PATH    Input to solve. Defaults to $AOC_INPUT_DIR/inputNN.txt
+                        or ./data/inputNN.txt.
+        --interval MS   Check for changes every MS milliseconds. Defaults
+                        to 500.
+        --root PATH     Crate the day is part of. Defaults to the current
+                        directory.
+    -h, --help          Print this help";
+
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut day = None;
+    let mut input = None;
+    let mut interval = Duration::from_millis(500);
+    let mut root = PathBuf::from(".");
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            "-i" | "--input" => {
+                input = Some(args.next().context("Missing input path")?);
+            }
+            "--interval" => {
+                let ms = args.next().context("Missing interval")?;
+                interval = Duration::from_millis(
+                    ms.parse().context("Invalid interval")?,
+                );
+            }
+            "--root" => {
+                root = PathBuf::from(args.next().context("Missing root path")?);
+            }
+            _ if arg.starts_with('-') => {
+                bail!("Unknown option '{}'\n{}", arg, USAGE)
+            }
+            _ if day.is_some() => bail!("Too many arguments\n{}", USAGE),
+            _ => day = Some(arg.parse::<u32>().context("Invalid day")?),
+        }
+    }
+
+    let day = day.with_context(|| format!("Missing DAY\n{}", USAGE))?;
+    let day =
+        find_day(day).with_context(|| format!("Day {} is not solved", day))?;
+    let input = match InputSource::resolve(day.day, input.as_deref()) {
+        // Cargo runs from the root, which may not be the current directory.
+        InputSource::Path(path) => env::current_dir()?.join(path),
+        InputSource::Stdin => bail!("Can not watch stdin"),
+    };
+    let source = root.join(format!("src/day{:02}.rs", day.day));
+
+    let mut watcher = Watcher::new(vec![source, input.clone()]);
+    let mut previous = None;
+    let mut runs = 0;
+
+    loop {
+        let changed: Vec<_> = watcher
+            .changed()
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+
+        if !changed.is_empty() {
+            let current = solve(&root, day.day, &input)?;
+            runs += 1;
+
+            let changed = changed.join(", ");
+            println!("Day {}, run {}: {} changed", day.day, runs, changed);
+            print!("{}", render(previous.as_deref(), &current));
+            previous = Some(current);
+        }
+
+        thread::sleep(interval);
+    }
+}
+
+/// Polls the modification times of files.
+struct Watcher {
+    paths: Vec<PathBuf>,
+    mtimes: Vec<Option<Option<SystemTime>>>,
+}
+
+impl Watcher {
+    fn new(paths: Vec<PathBuf>) -> Watcher {
+        let mtimes = vec![None; paths.len()];
+
+        Watcher {
+            paths,
+            mtimes,
+        }
+    }
+
+    /// The paths that were modified, created or removed since the last
+    /// call. All of them on the first call.
+    fn changed(&mut self) -> Vec<&Path> {
+        let mut changed = Vec::new();
+
+        for (path, seen) in self.paths.iter().zip(&mut self.mtimes) {
+            let mtime = fs::metadata(path).and_then(|m| m.modified()).ok();
+
+            if *seen != Some(mtime) {
+                *seen = Some(mtime);
+                changed.push(path.as_path());
+            }
+        }
+
+        changed
+    }
+}
+
+/// Answer or error of a part.
+type Answer = (Part, Result<String, String>);
+
+/// Rebuilds and runs the solutions of `day` on `input`. A failed build
+/// fails every part.
+fn solve(root: &Path, day: u32, input: &Path) -> Result<Vec<Answer>> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+    let out = Command::new(cargo)
+        .args(["run", "--quiet", "--release", "--bin", "aoc", "--"])
+        .arg(day.to_string())
+        .args(["--format", "csv", "-i"])
+        .arg(input)
+        .current_dir(root)
+        .stderr(Stdio::inherit())
+        .output()
+        .context("Failed to run cargo")?;
+
+    if !out.status.success() {
+        return Ok(Part::BOTH
+            .iter()
+            .map(|&part| (part, Err("build failed".to_owned())))
+            .collect());
+    }
+
+    parse_csv(&String::from_utf8_lossy(&out.stdout))
+}
+
+/// Parses the answers out of `aoc --format csv` output.
+fn parse_csv(csv: &str) -> Result<Vec<Answer>> {
+    let mut lines = csv.lines();
+    let header = split_csv(lines.next().context("Missing CSV header")?);
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|h| h == name)
+            .with_context(|| format!("Missing '{}' column", name))
+    };
+    let (part, answer, error) =
+        (column("part")?, column("answer")?, column("error")?);
+
+    lines
+        .map(|ln| {
+            let fields = split_csv(ln);
+            let field = |i: usize| fields.get(i).context("Missing field");
+            let part: Part = field(part)?.parse()?;
+            let error = field(error)?;
+
+            if error.is_empty() {
+                Ok((part, Ok(field(answer)?.clone())))
+            } else {
+                Ok((part, Err(error.clone())))
+            }
+        })
+        .collect()
+}
+
+fn split_csv(ln: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = ln.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+
+    fields
+}
+
+/// Renders the `previous` and `current` answers side by side, marking the
+/// ones that changed.
+fn render(previous: Option<&[Answer]>, current: &[Answer]) -> String {
+    let show = |answer: Option<&Result<String, String>>| match answer {
+        Some(Ok(answer)) => answer.clone(),
+        Some(Err(e)) => format!("error: {}", e),
+        None => "-".to_owned(),
+    };
+    let rows: Vec<_> = current
+        .iter()
+        .map(|(part, answer)| {
+            let prev = previous
+                .and_then(|prev| prev.iter().find(|(p, _)| p == part))
+                .map(|(_, answer)| answer);
+            let changed = previous.is_some() && prev != Some(answer);
+
+            (part, show(prev), show(Some(answer)), changed)
+        })
+        .collect();
+
+    let width =
+        rows.iter().map(|row| row.1.len()).fold("Previous".len(), usize::max);
+    let mut s = format!("Part  {:<width$}  Current\n", "Previous");
+
+    for (part, prev, cur, changed) in rows {
+        let mark = if changed {
+            "  (changed)"
+        } else {
+            ""
+        };
+        writeln!(s, "{:>4}  {:<width$}  {}{}", part, prev, cur, mark).unwrap();
+    }
+
+    s
+}
+
+#[test]
+fn test_parse_csv() {
+    let answers = parse_csv(
+        "day,part,answer,duration_ns,error\n\
+         7,1,337,1500,\n\
+         7,2,,20,\"not \"\"solved\"\", yet\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        answers,
+        [
+            (Part::One, Ok("337".to_owned())),
+            (Part::Two, Err("not \"solved\", yet".to_owned()))
+        ]
+    );
+}
+
+#[test]
+fn test_render() {
+    let previous =
+        [(Part::One, Ok("337".to_owned())), (Part::Two, Ok("5".to_owned()))];
+    let current =
+        [(Part::One, Ok("337".to_owned())), (Part::Two, Ok("6".to_owned()))];
+
+    assert_eq!(
+        render(None, &current),
+        "Part  Previous  Current\n   1  -         337\n   2  -         6\n"
+    );
+    assert_eq!(
+        render(Some(&previous), &current),
+        "Part  Previous  Current\n   1  337       337\n   2  5         6  (changed)\n"
+    );
+}
+
+#[test]
+fn test_watcher() {
+    let path =
+        env::temp_dir().join(format!("aoc-2020-watch-{}", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let mut watcher = Watcher::new(vec![path.clone()]);
+    assert_eq!(watcher.changed(), [path.as_path()]);
+    assert!(watcher.changed().is_empty());
+
+    fs::write(&path, "1").unwrap();
+    assert_eq!(watcher.changed(), [path.as_path()]);
+    assert!(watcher.changed().is_empty());
+
+    fs::remove_file(&path).unwrap();
+    assert_eq!(watcher.changed(), [path.as_path()]);
+}
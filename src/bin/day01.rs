@@ -0,0 +1,48 @@
+use anyhow::{bail, Result};
+use aoc_2020::day01::{k_sum, Day01};
+use aoc_2020::output::Format;
+use aoc_2020::{Args, Part};
+use std::env;
+
+/// Solves both parts, unless `--target N` or `--k K` are given, in which
+/// case every set of K entries adding up to N is printed instead. K
+/// defaults to 2 and N to 2020.
+fn main() -> Result<()> {
+    let args = Args::parse(env::args().skip(1), &["--target", "--k"], &[])?;
+
+    if !args.has_day_args() {
+        return aoc_2020::run_args::<Day01>(&args, &Part::BOTH);
+    }
+
+    if args.format != Format::Text {
+        bail!("--target and --k only print text");
+    }
+
+    let target = args.value::<u64>("--target")?.unwrap_or(2020);
+    let k = args.value::<usize>("--k")?.unwrap_or(2);
+    let nums = args.load::<Day01>()?;
+    let solutions = k_sum(&nums, target, k);
+
+    for solution in &solutions {
+        let terms: Vec<_> = solution.iter().map(u32::to_string).collect();
+        let product = solution
+            .iter()
+            .try_fold(1u64, |acc, &n| acc.checked_mul(u64::from(n)));
+
+        match product {
+            Some(product) => {
+                println!(
+                    "{} = {}, product {}",
+                    terms.join(" + "),
+                    target,
+                    product
+                )
+            }
+            None => println!("{} = {}", terms.join(" + "), target),
+        }
+    }
+
+    println!("{} solutions", solutions.len());
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anyhow::{bail, Result};
+use aoc_2020::day02::{parse_policy, rejections, CountPolicy, Day02, Unit};
+use aoc_2020::output::Format;
+use aoc_2020::{Args, Part};
+use std::env;
+
+/// Solves both parts, unless `--policy SPEC` or `--report` are given, in
+/// which case the passwords are checked against every policy given instead,
+/// `count` by default. `--report` lists the rejected passwords along with
+/// the clauses they break.
+fn main() -> Result<()> {
+    let args = Args::parse(env::args().skip(1), &["--policy"], &["--report"])?;
+
+    if !args.has_day_args() {
+        return aoc_2020::run_args::<Day02>(&args, &Part::BOTH);
+    }
+
+    if args.format != Format::Text {
+        bail!("--policy and --report only print text");
+    }
+
+    let mut policies = args
+        .values("--policy")
+        .map(parse_policy)
+        .collect::<Result<Vec<_>>>()?;
+
+    if policies.is_empty() {
+        policies.push(Box::new(CountPolicy(Unit::Char)));
+    }
+
+    let entries = args.load::<Day02>()?;
+    let rejected = rejections(&entries, &policies);
+
+    if args.has("--report") {
+        for rejection in &rejected {
+            println!("{}", rejection);
+        }
+    }
+
+    println!(
+        "{} of {} passwords valid",
+        entries.len() - rejected.len(),
+        entries.len()
+    );
+
+    Ok(())
+}
@@ -1,92 +1,52 @@
 use anyhow::{bail, Result};
-use aoc_2020::file_lines;
-use std::path::Path;
-
-struct GeoMap {
-    // true: has tree
-    // false: no tree
-    trees_grid: Vec<Vec<bool>>,
-}
-
-impl GeoMap {
-    fn load(path: impl AsRef<Path>) -> Result<Self> {
-        let trees_grid = file_lines(path)?
-            .map(|ln| {
-                ln.chars()
-                    .map(|c| match c {
-                        '#' => Ok(true),
-                        '.' => Ok(false),
-                        _ => bail!("Invalid geomap"),
-                    })
-                    .collect::<Result<Vec<_>>>()
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        if trees_grid.is_empty() {
-            bail!("Empty geomap");
-        }
+use aoc_2020::day03::{find_trees_with_slope, search, Day03, Slope};
+use aoc_2020::output::Format;
+use aoc_2020::{Args, Part};
+use std::env;
+
+/// Solves both parts, unless `--slope RIGHT,DOWN` or `--search N` are
+/// given. `--slope`, which can be repeated, counts the trees hit on each
+/// slope and their product. `--search` reports the slopes moving at most N
+/// cells each way per step that hit the fewest and the most trees.
+fn main() -> Result<()> {
+    let args = Args::parse(env::args().skip(1), &["--slope", "--search"], &[])?;
 
-        Ok(GeoMap {
-            trees_grid,
-        })
+    if !args.has_day_args() {
+        return aoc_2020::run_args::<Day03>(&args, &Part::BOTH);
     }
 
-    fn has_tree(&self, x: usize, y: usize) -> bool {
-        let grid_x = x % self.trees_grid.len();
-        let grid_y = y % self.trees_grid[0].len();
-
-        self.trees_grid[grid_x][grid_y]
+    if args.format != Format::Text {
+        bail!("--slope and --search only print text");
     }
 
-    fn num_of_cols(&self) -> usize {
-        self.trees_grid.len()
-    }
+    let slopes = args
+        .values("--slope")
+        .map(str::parse)
+        .collect::<Result<Vec<Slope>>>()?;
+    let bound = args.value::<usize>("--search")?;
+    let geomap = args.load::<Day03>()?;
 
-    #[allow(dead_code)]
-    fn print_grid(&self) {
-        for row in &self.trees_grid {
-            for tree in row {
-                if *tree {
-                    print!("#");
-                } else {
-                    print!(".");
-                }
-            }
-            println!();
+    if !slopes.is_empty() {
+        let mut product = 1u64;
+
+        for &slope in &slopes {
+            let trees = find_trees_with_slope(&geomap, slope);
+            product = product.saturating_mul(trees as u64);
+            println!("{}: {} trees", slope, trees);
         }
-    }
-}
 
-fn find_trees_with_slope(geomap: &GeoMap, right: usize, down: usize) -> usize {
-    let mut trees_found = 0;
-    let mut x = 0;
-    let mut y = 0;
+        println!("product: {}", product);
+    }
 
-    while x < geomap.num_of_cols() {
-        y += right;
-        x += down;
+    if let Some(bound) = bound {
+        let (fewest, most) = search(&geomap, bound)?;
 
-        if geomap.has_tree(x, y) {
-            trees_found += 1;
+        for (name, hits) in [("fewest", fewest), ("most", most)] {
+            let slopes: Vec<_> =
+                hits.slopes.iter().map(Slope::to_string).collect();
+            println!("{} trees ({}): {}", name, hits.trees, slopes.join("; "));
         }
     }
 
-    trees_found
-}
-
-fn main() -> Result<()> {
-    let geomap = GeoMap::load("./data/input03.txt")?;
-
-    println!("part 1 answer: {}", find_trees_with_slope(&geomap, 3, 1));
-
-    let part2_res = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].iter().fold(
-        1,
-        |acc, (right, down)| {
-            acc * find_trees_with_slope(&geomap, *right, *down)
-        },
-    );
-
-    println!("part 2 anwser: {}", part2_res);
-
     Ok(())
 }
@@ -0,0 +1,156 @@
+use anyhow::{bail, Context, Result};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::submit::Verdict;
+use crate::Part;
+
+/// Environment variable holding the session cookie of adventofcode.com.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Environment variable overriding `DEFAULT_BASE_URL`.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const YEAR: u32 = 2020;
+const USER_AGENT: &str = "github.com/oblique/aoc-2020";
+
+/// Client of the Advent of Code website, or of anything serving the same
+/// endpoints under `base_url`.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(USER_AGENT)
+            .build();
+
+        Client {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+            agent,
+        }
+    }
+
+    /// Creates a client from `$AOC_SESSION` and `$AOC_BASE_URL`, unless
+    /// they are given explicitly.
+    pub fn from_env(
+        base_url: Option<&str>,
+        session: Option<&str>,
+    ) -> Result<Client> {
+        let session = match session {
+            Some(session) => session.to_owned(),
+            None => env::var(SESSION_ENV).ok().with_context(|| {
+                format!("No session token, set ${} or --session", SESSION_ENV)
+            })?,
+        };
+        let base_url = match base_url {
+            Some(base_url) => base_url.to_owned(),
+            None => env::var(BASE_URL_ENV)
+                .unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned()),
+        };
+
+        Ok(Client::new(&base_url, &session))
+    }
+
+    fn day_url(&self, day: u32) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Downloads the input of `day`.
+    pub fn fetch_input(&self, day: u32) -> Result<String> {
+        let url = format!("{}/input", self.day_url(day));
+
+        let resp =
+            match self.agent.get(&url).set("Cookie", &self.cookie()).call() {
+                Ok(resp) => resp,
+                Err(ureq::Error::Status(code, resp)) => {
+                    let body = resp.into_string().unwrap_or_default();
+                    bail!("Failed to fetch {}: {} {}", url, code, body.trim());
+                }
+                Err(e) => {
+                    return Err(e)
+                        .with_context(|| format!("Failed to fetch {}", url))
+                }
+            };
+
+        resp.into_string()
+            .with_context(|| format!("Failed to read response of {}", url))
+    }
+
+    /// Submits `answer` as the answer of `part` of `day`.
+    pub fn submit(
+        &self,
+        day: u32,
+        part: Part,
+        answer: &str,
+    ) -> Result<Verdict> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.to_string();
+
+        let resp = match self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level), ("answer", answer)])
+        {
+            Ok(resp) => resp,
+            Err(ureq::Error::Status(code, resp)) => {
+                let body = resp.into_string().unwrap_or_default();
+                bail!("Failed to submit to {}: {} {}", url, code, body.trim());
+            }
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Failed to submit to {}", url))
+            }
+        };
+
+        let body = resp
+            .into_string()
+            .with_context(|| format!("Failed to read response of {}", url))?;
+
+        Ok(Verdict::parse(&body))
+    }
+
+    /// Downloads the input of `day` into `dir`, unless it is already there.
+    pub fn fetch_to_dir(&self, day: u32, dir: &Path) -> Result<Fetched> {
+        let path = dir.join(format!("input{:02}.txt", day));
+
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let input = self.fetch_input(day)?;
+
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create '{}'", dir.display()))?;
+
+        // Write to a temporary file first so that a failed write never
+        // leaves a truncated input behind to be treated as cached.
+        let tmp = path.with_extension("txt.part");
+        fs::write(&tmp, input)
+            .with_context(|| format!("Failed to write '{}'", tmp.display()))?;
+        fs::rename(&tmp, &path)
+            .with_context(|| format!("Failed to write '{}'", path.display()))?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+}
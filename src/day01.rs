@@ -0,0 +1,170 @@
+use anyhow::{bail, Result};
+
+use crate::parse::{filter_lines, parse_span};
+use crate::Solution;
+
+pub struct Day01;
+
+/// All the distinct sets of `k` entries of `nums` that add up to `target`,
+/// as sorted values. An entry is never used twice, but equal entries may
+/// all be used.
+///
+/// Runs in O(n log n) for `k` up to 2, and O(n^(k-1)) above.
+pub fn k_sum(nums: &[u32], target: u64, k: usize) -> Vec<Vec<u32>> {
+    let mut nums = nums.to_vec();
+    let mut solutions = Vec::new();
+
+    nums.sort_unstable();
+    k_sum_sorted(&nums, target, k, &mut Vec::new(), &mut solutions);
+
+    solutions
+}
+
+fn k_sum_sorted(
+    nums: &[u32],
+    target: u64,
+    k: usize,
+    prefix: &mut Vec<u32>,
+    solutions: &mut Vec<Vec<u32>>,
+) {
+    match k {
+        0 => {
+            if target == 0 {
+                solutions.push(prefix.clone());
+            }
+        }
+        1 => {
+            if nums.binary_search_by(|&n| u64::from(n).cmp(&target)).is_ok() {
+                let mut solution = prefix.clone();
+                solution.push(target as u32);
+                solutions.push(solution);
+            }
+        }
+        2 => {
+            if nums.is_empty() {
+                return;
+            }
+
+            let (mut lo, mut hi) = (0, nums.len() - 1);
+
+            while lo < hi {
+                let sum = u64::from(nums[lo]) + u64::from(nums[hi]);
+
+                if sum < target {
+                    lo += 1;
+                } else if sum > target {
+                    hi -= 1;
+                } else {
+                    let mut solution = prefix.clone();
+                    solution.extend([nums[lo], nums[hi]]);
+                    solutions.push(solution);
+
+                    // Skip the duplicates of both ends.
+                    let (l, h) = (nums[lo], nums[hi]);
+                    while lo < hi && nums[lo] == l {
+                        lo += 1;
+                    }
+                    while lo < hi && nums[hi] == h {
+                        hi -= 1;
+                    }
+                }
+            }
+        }
+        _ => {
+            for i in 0..nums.len() {
+                let n = u64::from(nums[i]);
+
+                // Entries are sorted, so the rest can only be larger. A sum
+                // that overflows is larger than any target.
+                let min = n.checked_mul(k as u64);
+                if min.is_none_or(|min| min > target) {
+                    break;
+                }
+
+                if i > 0 && nums[i] == nums[i - 1] {
+                    continue;
+                }
+
+                prefix.push(nums[i]);
+                k_sum_sorted(
+                    &nums[i + 1..],
+                    target - n,
+                    k - 1,
+                    prefix,
+                    solutions,
+                );
+                prefix.pop();
+            }
+        }
+    }
+}
+
+/// Product of the only `k` entries adding up to 2020.
+fn solve(nums: &[u32], k: usize) -> Result<u64> {
+    match &k_sum(nums, 2020, k)[..] {
+        [solution] => Ok(solution.iter().map(|&n| u64::from(n)).product()),
+        [] => bail!("No {} entries add up to 2020", k),
+        solutions => {
+            bail!("{} sets of {} entries add up to 2020", solutions.len(), k)
+        }
+    }
+}
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    type Input = Vec<u32>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, false)
+    }
+
+    fn parse_with(input: &str, strict: bool) -> Result<Self::Input> {
+        filter_lines(input, strict, |ln| parse_span(input, ln))
+    }
+
+    fn part1(nums: &Self::Input) -> Result<u64> {
+        solve(nums, 2)
+    }
+
+    fn part2(nums: &Self::Input) -> Result<u64> {
+        solve(nums, 3)
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = Day01::parse("1721\n979\n366\n299\n675\n1456\n").unwrap();
+
+    assert_eq!(Day01::part1(&input).unwrap(), 514579);
+    assert_eq!(Day01::part2(&input).unwrap(), 241861950);
+}
+
+#[test]
+fn test_k_sum() {
+    // 1010 only adds up to 2020 with itself, which is a single entry.
+    assert!(k_sum(&[1010, 5, 7], 2020, 2).is_empty());
+    assert_eq!(k_sum(&[1010, 1010, 5], 2020, 2), [[1010, 1010]]);
+
+    assert_eq!(
+        k_sum(&[4, 1, 3, 2, 3, 5, 0], 6, 2),
+        [vec![1, 5], vec![2, 4], vec![3, 3]]
+    );
+    assert_eq!(
+        k_sum(&[1, 2, 3, 4, 5, 6], 10, 3),
+        [vec![1, 3, 6], vec![1, 4, 5], vec![2, 3, 5]]
+    );
+    assert_eq!(k_sum(&[2, 2, 2, 2, 2], 8, 4), [[2, 2, 2, 2]]);
+    assert_eq!(k_sum(&[1, 2], 3, 1), Vec::<Vec<u32>>::new());
+    assert_eq!(k_sum(&[1, 2], 2, 1), [[2]]);
+    assert_eq!(k_sum(&[1, 2], 0, 0), [Vec::<u32>::new()]);
+    assert!(k_sum(&[1, 2], 3, 3).is_empty());
+
+    assert!(k_sum(&[u32::MAX, u32::MAX], u64::MAX, usize::MAX).is_empty());
+    assert_eq!(
+        k_sum(&[u32::MAX; 3], 3 * u64::from(u32::MAX), 3),
+        [[u32::MAX; 3]]
+    );
+}
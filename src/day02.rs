@@ -0,0 +1,517 @@
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::parse::filter_lines;
+use crate::Solution;
+
+pub struct Day02;
+
+/// The `N-M L` part of a line, which each policy reads differently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub n: usize,
+    pub m: usize,
+    /// A single grapheme, which may be made of several characters.
+    pub letter: String,
+}
+
+impl Rule {
+    fn parse(s: &str) -> Result<Self> {
+        let (nums, letter) =
+            s.split_once(' ').context("Missing policy letter")?;
+        let (n, m) = nums.split_once('-').context("Expected 'N-M'")?;
+        let mut letter = letter.graphemes(true);
+
+        match (letter.next(), letter.next()) {
+            (Some(l), None) => Ok(Rule {
+                n: n.parse().context("Invalid policy number")?,
+                m: m.parse().context("Invalid policy number")?,
+                letter: l.to_owned(),
+            }),
+            _ => bail!("Expected a single policy letter"),
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{} {}", self.n, self.m, self.letter)
+    }
+}
+
+/// What a position in a password counts.
+///
+/// Only graphemes match what is displayed as a single letter: an accented
+/// letter may be one or two characters, and a character up to four bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Byte,
+    Char,
+    Grapheme,
+}
+
+impl Unit {
+    /// Whether `letter` starts at `pos`, starting at 0.
+    fn is_at(self, passwd: &str, pos: usize, letter: &str) -> bool {
+        match self {
+            Unit::Byte => passwd
+                .as_bytes()
+                .get(pos..)
+                .is_some_and(|rest| rest.starts_with(letter.as_bytes())),
+            Unit::Char => passwd
+                .char_indices()
+                .nth(pos)
+                .is_some_and(|(i, _)| passwd[i..].starts_with(letter)),
+            Unit::Grapheme => passwd.graphemes(true).nth(pos) == Some(letter),
+        }
+    }
+
+    /// Number of times `letter` appears. Characters and bytes find it even
+    /// as part of a grapheme, e.g. `e` in `e\u{301}`.
+    fn count(self, passwd: &str, letter: &str) -> usize {
+        match self {
+            Unit::Byte | Unit::Char => passwd.matches(letter).count(),
+            Unit::Grapheme => {
+                passwd.graphemes(true).filter(|&g| g == letter).count()
+            }
+        }
+    }
+
+    fn distinct(self, passwd: &str) -> usize {
+        match self {
+            Unit::Byte => passwd.bytes().collect::<BTreeSet<_>>().len(),
+            Unit::Char => passwd.chars().collect::<BTreeSet<_>>().len(),
+            Unit::Grapheme => {
+                passwd.graphemes(true).collect::<BTreeSet<_>>().len()
+            }
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Unit::Byte => "byte",
+            Unit::Char => "char",
+            Unit::Grapheme => "grapheme",
+        }
+    }
+}
+
+impl FromStr for Unit {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "byte" => Ok(Unit::Byte),
+            "char" => Ok(Unit::Char),
+            "grapheme" => Ok(Unit::Grapheme),
+            _ => bail!("Unknown position unit '{}'", s),
+        }
+    }
+}
+
+/// Writes the spec `name[@unit]`, leaving out the default unit.
+fn write_spec(f: &mut fmt::Formatter, name: &str, unit: Unit) -> fmt::Result {
+    match unit {
+        Unit::Char => f.write_str(name),
+        _ => write!(f, "{}@{}", name, unit.name()),
+    }
+}
+
+/// A way of checking passwords, possibly against the rule they are listed
+/// with. Displays as the spec `parse_policy` reads it from.
+pub trait Policy: Display + Send + Sync {
+    /// The clause of the policy that `passwd` breaks, if any.
+    fn violation(&self, rule: &Rule, passwd: &str) -> Option<String>;
+
+    fn is_valid(&self, rule: &Rule, passwd: &str) -> bool {
+        self.violation(rule, passwd).is_none()
+    }
+}
+
+/// The letter appears between `n` and `m` times.
+pub struct CountPolicy(pub Unit);
+
+impl Policy for CountPolicy {
+    fn violation(&self, rule: &Rule, passwd: &str) -> Option<String> {
+        let count = self.0.count(passwd, &rule.letter);
+
+        if count >= rule.n && count <= rule.m {
+            None
+        } else {
+            Some(format!(
+                "'{}' appears {} times, not {}-{}",
+                rule.letter, count, rule.n, rule.m
+            ))
+        }
+    }
+}
+
+impl Display for CountPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_spec(f, "count", self.0)
+    }
+}
+
+/// How many of the positions the letter must be at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Positions {
+    /// Exactly one.
+    Xor,
+    /// Both.
+    And,
+    /// At least one.
+    Or,
+}
+
+/// The letter is at the positions `n` and `m`, starting at 1, as many times
+/// as `Positions` requires.
+pub struct PositionPolicy(pub Positions, pub Unit);
+
+impl Policy for PositionPolicy {
+    fn violation(&self, rule: &Rule, passwd: &str) -> Option<String> {
+        let (l, n, m) = (&rule.letter, rule.n, rule.m);
+
+        if n == 0 || m == 0 {
+            return Some("position 0 does not exist, they start at 1".into());
+        }
+
+        let at = |pos: usize| self.1.is_at(passwd, pos - 1, l);
+
+        match (self.0, at(n), at(m)) {
+            (Positions::Xor, true, true) => {
+                Some(format!("'{}' is at both positions {} and {}", l, n, m))
+            }
+            (Positions::And, false, _) => {
+                Some(format!("'{}' is not at position {}", l, n))
+            }
+            (Positions::And, _, false) => {
+                Some(format!("'{}' is not at position {}", l, m))
+            }
+            (Positions::Xor, false, false) | (Positions::Or, false, false) => {
+                Some(format!("'{}' is at neither position {} nor {}", l, n, m))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Display for PositionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.0 {
+            Positions::Xor => "xor",
+            Positions::And => "and",
+            Positions::Or => "or",
+        };
+
+        write_spec(f, name, self.1)
+    }
+}
+
+/// The password matches a regular expression, regardless of the rule.
+pub struct RegexPolicy(pub Regex);
+
+impl Policy for RegexPolicy {
+    fn violation(&self, _: &Rule, passwd: &str) -> Option<String> {
+        if self.0.is_match(passwd) {
+            None
+        } else {
+            Some(format!("does not match /{}/", self.0))
+        }
+    }
+}
+
+impl Display for RegexPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "regex:{}", self.0)
+    }
+}
+
+/// The password has at least this many distinct units, regardless of the
+/// rule.
+pub struct DistinctPolicy(pub usize, pub Unit);
+
+impl Policy for DistinctPolicy {
+    fn violation(&self, _: &Rule, passwd: &str) -> Option<String> {
+        let distinct = self.1.distinct(passwd);
+
+        if distinct >= self.0 {
+            None
+        } else {
+            Some(format!(
+                "has {} distinct {}s, not at least {}",
+                distinct,
+                self.1.name(),
+                self.0
+            ))
+        }
+    }
+}
+
+impl Display for DistinctPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_spec(f, "distinct", self.1)?;
+        write!(f, ":{}", self.0)
+    }
+}
+
+/// Parses a policy spec: `count`, `xor`, `and`, `or`, `regex:PATTERN` or
+/// `distinct:N`. All but `regex` take the unit of positions after an `@`,
+/// e.g. `xor@grapheme`, and count characters by default.
+pub fn parse_policy(spec: &str) -> Result<Box<dyn Policy>> {
+    let (name, arg) = match spec.split_once(':') {
+        Some((name, arg)) => (name, Some(arg)),
+        None => (spec, None),
+    };
+    let (name, unit) = match name.split_once('@') {
+        Some((name, unit)) => (name, Some(unit.parse::<Unit>()?)),
+        None => (name, None),
+    };
+    let u = unit.unwrap_or(Unit::Char);
+
+    Ok(match (name, arg) {
+        ("count", None) => Box::new(CountPolicy(u)),
+        ("xor", None) => Box::new(PositionPolicy(Positions::Xor, u)),
+        ("and", None) => Box::new(PositionPolicy(Positions::And, u)),
+        ("or", None) => Box::new(PositionPolicy(Positions::Or, u)),
+        ("regex", Some(pattern)) if unit.is_none() => Box::new(RegexPolicy(
+            Regex::new(pattern).context("Invalid policy regex")?,
+        )),
+        ("distinct", Some(n)) => Box::new(DistinctPolicy(
+            n.parse().context("Invalid number of distinct units")?,
+            u,
+        )),
+        _ => bail!("Unknown policy '{}'", spec),
+    })
+}
+
+/// A password along with the rule it was listed with.
+pub struct Entry {
+    pub rule: Rule,
+    pub passwd: String,
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.rule, self.passwd)
+    }
+}
+
+/// An entry along with the policies it breaks, and how.
+pub struct Rejection<'a> {
+    pub entry: &'a Entry,
+    /// The spec and broken clause of each policy.
+    pub failures: Vec<(String, String)>,
+}
+
+impl Display for Rejection<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.entry)?;
+
+        for (policy, clause) in &self.failures {
+            write!(f, "\n    {}: {}", policy, clause)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The entries that break any of `policies`.
+pub fn rejections<'a>(
+    entries: &'a [Entry],
+    policies: &[Box<dyn Policy>],
+) -> Vec<Rejection<'a>> {
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let failures: Vec<_> = policies
+                .iter()
+                .filter_map(|policy| {
+                    let clause =
+                        policy.violation(&entry.rule, &entry.passwd)?;
+                    Some((policy.to_string(), clause))
+                })
+                .collect();
+
+            if failures.is_empty() {
+                None
+            } else {
+                Some(Rejection {
+                    entry,
+                    failures,
+                })
+            }
+        })
+        .collect()
+}
+
+/// Number of entries whose password is valid under `policy`.
+pub fn count_valid(entries: &[Entry], policy: &dyn Policy) -> usize {
+    entries
+        .iter()
+        .filter(|entry| policy.is_valid(&entry.rule, &entry.passwd))
+        .count()
+}
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<Entry>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, false)
+    }
+
+    fn parse_with(input: &str, strict: bool) -> Result<Self::Input> {
+        filter_lines(input, strict, |ln| {
+            let (rule, passwd) = ln.split_once(':').context("Missing ':'")?;
+            let rule = Rule::parse(rule)?;
+
+            Ok(Entry {
+                rule,
+                passwd: passwd.trim().to_owned(),
+            })
+        })
+    }
+
+    fn part1(entries: &Self::Input) -> Result<usize> {
+        Ok(count_valid(entries, &CountPolicy(Unit::Char)))
+    }
+
+    fn part2(entries: &Self::Input) -> Result<usize> {
+        Ok(count_valid(entries, &PositionPolicy(Positions::Xor, Unit::Char)))
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = Day02::parse(
+        "1-3 a: abcde\n\
+         1-3 b: cdefg\n\
+         2-9 c: ccccccccc\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day02::part1(&input).unwrap(), 2);
+    assert_eq!(Day02::part2(&input).unwrap(), 1);
+}
+
+#[test]
+fn test_policies() {
+    let input = Day02::parse(
+        "1-3 a: abcde\n\
+         1-3 b: cdefg\n\
+         2-9 c: ccccccccc\n\
+         1-2 x: xxy\n",
+    )
+    .unwrap();
+    let valid = |spec: &str| count_valid(&input, &*parse_policy(spec).unwrap());
+
+    assert_eq!(valid("count"), 3);
+    assert_eq!(valid("xor"), 1);
+    assert_eq!(valid("and"), 2);
+    assert_eq!(valid("or"), 3);
+    assert_eq!(valid("regex:^[a-e]+$"), 2);
+    assert_eq!(valid("distinct:3"), 2);
+
+    assert!(parse_policy("regex:(").is_err());
+    assert!(parse_policy("distinct").is_err());
+    assert!(parse_policy("count:1").is_err());
+    assert_eq!(parse_policy("regex:^a").unwrap().to_string(), "regex:^a");
+}
+
+#[test]
+fn test_position_zero() {
+    let input = Day02::parse("0-3 a: bbb\n1-3 a: abc\n").unwrap();
+    let policies = vec![parse_policy("count").unwrap()];
+
+    assert_eq!(input.len(), 2);
+    assert_eq!(Day02::part1(&input).unwrap(), 2);
+    assert_eq!(Day02::part2(&input).unwrap(), 1);
+    assert!(rejections(&input, &policies).is_empty());
+
+    let policies = vec![parse_policy("or").unwrap()];
+    let rejected: Vec<_> =
+        rejections(&input, &policies).iter().map(ToString::to_string).collect();
+
+    assert_eq!(
+        rejected,
+        ["0-3 a: bbb\n    or: position 0 does not exist, they start at 1"]
+    );
+}
+
+#[test]
+fn test_rejections() {
+    let input = Day02::parse("1-3 a: abcde\n2-9 c: ccccccccc\n").unwrap();
+    let policies =
+        vec![parse_policy("xor").unwrap(), parse_policy("distinct:2").unwrap()];
+    let rejected: Vec<_> =
+        rejections(&input, &policies).iter().map(ToString::to_string).collect();
+
+    assert_eq!(
+        rejected,
+        ["2-9 c: ccccccccc\n    \
+          xor: 'c' is at both positions 2 and 9\n    \
+          distinct:2: has 1 distinct chars, not at least 2"]
+    );
+}
+
+#[test]
+fn test_units() {
+    // Composed and decomposed accents, a ligature of emoji and a letter.
+    let passwd = "\u{e9}e\u{301}\u{1f468}\u{200d}\u{1f467}x";
+
+    assert!(Unit::Byte.is_at(passwd, 16, "x"));
+    assert!(Unit::Char.is_at(passwd, 6, "x"));
+    assert!(Unit::Grapheme.is_at(passwd, 3, "x"));
+    assert!(Unit::Char.is_at(passwd, 1, "e"));
+    assert!(!Unit::Grapheme.is_at(passwd, 1, "e"));
+    assert!(Unit::Grapheme.is_at(passwd, 1, "e\u{301}"));
+    assert!(!Unit::Byte.is_at(passwd, 1, "\u{e9}"));
+
+    assert_eq!(Unit::Char.count(passwd, "e"), 1);
+    assert_eq!(Unit::Grapheme.count(passwd, "e"), 0);
+    assert_eq!(Unit::Grapheme.count(passwd, "\u{1f467}"), 0);
+
+    assert_eq!(Unit::Byte.distinct(passwd), 14);
+    assert_eq!(Unit::Char.distinct(passwd), 7);
+    assert_eq!(Unit::Grapheme.distinct(passwd), 4);
+}
+
+#[test]
+fn test_unicode() {
+    let input = Day02::parse(
+        "1-2 \u{fc}: \u{fc}b\n\
+         2-3 \u{df}: a\u{df}\u{df}\n\
+         1-2 \u{1f600}: \u{1f600}\u{1f600}\n\
+         1-2 x: e\u{301}x\n\
+         2-2 e: e\u{301}e\n",
+    )
+    .unwrap();
+    let valid = |spec: &str| count_valid(&input, &*parse_policy(spec).unwrap());
+
+    assert_eq!(valid("xor@byte"), 3);
+    assert_eq!(valid("xor"), 1);
+    assert_eq!(valid("xor@grapheme"), 2);
+    assert_eq!(valid("count"), 5);
+    assert_eq!(valid("count@grapheme"), 4);
+    assert_eq!(valid("distinct@byte:3"), 5);
+    assert_eq!(valid("distinct:3"), 1);
+    assert_eq!(valid("distinct@grapheme:3"), 0);
+
+    let input = Day02::parse("1-1 e\u{301}: e\u{301}\n1-1 ab: a\n").unwrap();
+    assert_eq!(input.len(), 1);
+    assert_eq!(input[0].rule.letter, "e\u{301}");
+
+    let spec = |spec: &str| parse_policy(spec).map(|p| p.to_string());
+    assert_eq!(spec("xor@grapheme").unwrap(), "xor@grapheme");
+    assert_eq!(spec("distinct@byte:3").unwrap(), "distinct@byte:3");
+    assert_eq!(spec("count@char").unwrap(), "count");
+    assert!(spec("regex@byte:x").is_err());
+    assert!(spec("xor@word").is_err());
+}
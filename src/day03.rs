@@ -0,0 +1,343 @@
+use anyhow::{bail, Context, Result};
+use std::cmp::Ordering;
+use std::convert::TryInto;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::{Grid, Solution};
+
+pub struct Day03;
+
+pub struct GeoMap {
+    // true: has tree
+    // false: no tree
+    trees: Grid<bool>,
+}
+
+impl GeoMap {
+    pub fn parse(input: &str) -> Result<Self> {
+        let trees = Grid::parse(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(GeoMap {
+            trees,
+        })
+    }
+
+    /// The map repeats to the right forever.
+    fn has_tree(&self, x: isize, y: usize) -> bool {
+        *self.trees.get_wrapping(x, y as isize)
+    }
+
+    fn width(&self) -> usize {
+        self.trees.width()
+    }
+
+    fn height(&self) -> usize {
+        self.trees.height()
+    }
+}
+
+/// Direction of the toboggan, as the cells moved right and down by each
+/// step. Right may be negative, but down is always positive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slope {
+    right: isize,
+    down: usize,
+}
+
+impl Slope {
+    /// Returns `None` if the slope does not go down.
+    pub fn new(right: isize, down: usize) -> Option<Slope> {
+        if down == 0 {
+            return None;
+        }
+
+        Some(Slope {
+            right,
+            down,
+        })
+    }
+
+    pub fn right(&self) -> isize {
+        self.right
+    }
+
+    pub fn down(&self) -> usize {
+        self.down
+    }
+}
+
+/// Parses `RIGHT,DOWN`, where each may be a fraction `P/Q`. A fractional
+/// slope only lands on exact grid points, so `3/2,1` steps by 3 right and 2
+/// down.
+impl FromStr for Slope {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (right, down) =
+            s.split_once(',').context("Expected 'RIGHT,DOWN'")?;
+        let (p, q) = parse_fraction(right)?;
+        let (r, t) = parse_fraction(down)?;
+
+        if r <= 0 {
+            bail!("Slope must go down");
+        }
+
+        // Scale both fractions to the smallest step that is whole.
+        let step = || -> Option<(isize, usize)> {
+            let scale = (q / gcd(q, t)).checked_mul(t)?;
+            let right = p.checked_mul(scale / q)?.try_into().ok()?;
+            let down = r.checked_mul(scale / t)?.try_into().ok()?;
+            Some((right, down))
+        };
+        let (right, down) = step().context("Slope too large")?;
+
+        Slope::new(right, down).context("Slope must go down")
+    }
+}
+
+impl Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "right {}, down {}", self.right, self.down)
+    }
+}
+
+/// Parses `P` or `P/Q` into a reduced fraction with a positive denominator.
+fn parse_fraction(s: &str) -> Result<(i64, i64)> {
+    let (p, q) = match s.split_once('/') {
+        Some((p, q)) => (p, q),
+        None => (s, "1"),
+    };
+    let p: i64 = p.trim().parse().context("Invalid slope")?;
+    let q: i64 = q.trim().parse().context("Invalid slope")?;
+
+    if q <= 0 {
+        bail!("Slope denominators must be positive");
+    }
+
+    let d = gcd(p.checked_abs().context("Slope too large")?, q);
+
+    Ok((p / d, q / d))
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub fn find_trees_with_slope(geomap: &GeoMap, slope: Slope) -> usize {
+    let mut trees_found = 0;
+    let mut x = 0;
+    let mut y = 0;
+    // Only the position across the repeating map matters, and keeping to
+    // it avoids overflowing on steep slopes.
+    let width = geomap.width() as isize;
+    let right = slope.right % width;
+
+    while y + slope.down < geomap.height() {
+        x = (x + right).rem_euclid(width);
+        y += slope.down;
+
+        if geomap.has_tree(x, y) {
+            trees_found += 1;
+        }
+    }
+
+    trees_found
+}
+
+/// Slopes hitting the same number of trees.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hits {
+    pub trees: usize,
+    pub slopes: Vec<Slope>,
+}
+
+impl Hits {
+    /// Adds `slope` if it hits as many trees, or replaces the slopes with it
+    /// if it hits `better` than them.
+    fn add(&mut self, slope: Slope, trees: usize, better: Ordering) {
+        match trees.cmp(&self.trees) {
+            Ordering::Equal => self.slopes.push(slope),
+            order if order == better => {
+                self.trees = trees;
+                self.slopes = vec![slope];
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The slopes hitting the fewest and the most trees, among those moving
+/// at most `bound` cells right or left, and down, by each step.
+pub fn search(geomap: &GeoMap, bound: usize) -> Result<(Hits, Hits)> {
+    if bound == 0 {
+        bail!("Search bound must be at least 1");
+    }
+
+    let b = bound as isize;
+    let mut fewest = Hits {
+        trees: usize::MAX,
+        slopes: Vec::new(),
+    };
+    let mut most = Hits {
+        trees: 0,
+        slopes: Vec::new(),
+    };
+
+    for down in 1..=bound {
+        for right in -b..=b {
+            let slope = Slope {
+                right,
+                down,
+            };
+            let trees = find_trees_with_slope(geomap, slope);
+
+            fewest.add(slope, trees, Ordering::Less);
+            most.add(slope, trees, Ordering::Greater);
+        }
+    }
+
+    Ok((fewest, most))
+}
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    type Input = GeoMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        GeoMap::parse(input)
+    }
+
+    fn part1(geomap: &GeoMap) -> Result<usize> {
+        Ok(find_trees_with_slope(
+            geomap,
+            Slope {
+                right: 3,
+                down: 1,
+            },
+        ))
+    }
+
+    fn part2(geomap: &GeoMap) -> Result<usize> {
+        let res = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].iter().fold(
+            1,
+            |acc, &(right, down)| {
+                acc * find_trees_with_slope(
+                    geomap,
+                    Slope {
+                        right,
+                        down,
+                    },
+                )
+            },
+        );
+
+        Ok(res)
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = Day03::parse(
+        "..##.......\n\
+         #...#...#..\n\
+         .#....#..#.\n\
+         ..#.#...#.#\n\
+         .#...##..#.\n\
+         ..#.##.....\n\
+         .#.#.#....#\n\
+         .#........#\n\
+         #.##...#...\n\
+         #...##....#\n\
+         .#..#...#.#\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day03::part1(&input).unwrap(), 7);
+    assert_eq!(Day03::part2(&input).unwrap(), 336);
+}
+
+#[test]
+fn test_slopes() {
+    let slope = |s: &str| {
+        let slope = s.parse::<Slope>().unwrap();
+        (slope.right(), slope.down())
+    };
+
+    assert_eq!(slope("3,1"), (3, 1));
+    assert_eq!(slope("-1,2"), (-1, 2));
+    assert_eq!(slope("2,2"), (2, 2));
+    assert_eq!(slope("3/2,1"), (3, 2));
+    assert_eq!(slope("1,1/2"), (2, 1));
+    assert_eq!(slope("-2/4, 1/3"), (-3, 2));
+    assert_eq!(slope("0,4/2"), (0, 2));
+
+    assert!("1,0".parse::<Slope>().is_err());
+    assert!("1,-1".parse::<Slope>().is_err());
+    assert!("1/0,1".parse::<Slope>().is_err());
+    assert!("1".parse::<Slope>().is_err());
+    assert!("-9223372036854775808,1".parse::<Slope>().is_err());
+    assert_eq!(slope("-9223372036854775807,1"), (-9223372036854775807, 1));
+    assert!("1/9223372036854775807,1/2".parse::<Slope>().is_err());
+
+    assert_eq!(Slope::new(1, 0), None);
+    assert!(Slope::new(-1, 1).is_some());
+}
+
+#[test]
+fn test_search() {
+    // Trees below the start, and on both sides of it on every other row.
+    let geomap = GeoMap::parse(
+        ".....\n\
+         #....\n\
+         ##..#\n\
+         #....\n\
+         ##...\n",
+    )
+    .unwrap();
+
+    let slope = |right, down| Slope::new(right, down).unwrap();
+    let trees =
+        |right, down| find_trees_with_slope(&geomap, slope(right, down));
+
+    assert_eq!(trees(0, 1), 4);
+    assert_eq!(trees(-1, 1), 1);
+    assert_eq!(trees(-1, 2), 1);
+    assert_eq!(trees(-3, 2), 0);
+    assert_eq!(trees(0, 2), 2);
+    assert_eq!(trees(0, 5), 0);
+    assert_eq!(trees(isize::MIN, 1), 1);
+
+    let (fewest, most) = search(&geomap, 1).unwrap();
+
+    assert_eq!(
+        fewest,
+        Hits {
+            trees: 0,
+            slopes: vec![slope(1, 1)],
+        }
+    );
+    assert_eq!(
+        most,
+        Hits {
+            trees: 4,
+            slopes: vec![slope(0, 1)],
+        }
+    );
+
+    let (_, most) = search(&geomap, 2).unwrap();
+
+    assert_eq!(most.slopes, [slope(0, 1)]);
+    assert!(search(&geomap, 0).is_err());
+}
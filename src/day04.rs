@@ -0,0 +1,296 @@
+#![allow(dead_code)]
+
+use anyhow::Result;
+
+use crate::{groups, ParseError, Solution};
+
+pub struct Day04;
+
+#[derive(Debug, Clone)]
+pub struct Passport {
+    birth_year: u32,
+    issue_year: u32,
+    expiration_year: u32,
+    height: Height,
+    hair_color: String,
+    eye_color: String,
+    passport_id: String,
+    country_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Height {
+    Cm(u32),
+    In(u32),
+}
+
+impl Passport {
+    fn parse(s: &str) -> Option<Passport> {
+        let mut birth_year = None;
+        let mut issue_year = None;
+        let mut expiration_year = None;
+        let mut height = None;
+        let mut hair_color = None;
+        let mut eye_color = None;
+        let mut passport_id = None;
+        let mut country_id = None;
+
+        for field in s.split_whitespace() {
+            let mut split = field.splitn(2, ':');
+            let key = split.next()?;
+            let val = split.next()?.trim();
+
+            if val.is_empty() {
+                continue;
+            }
+
+            match key {
+                "byr" => birth_year = val.parse::<u32>().ok(),
+                "iyr" => issue_year = val.parse::<u32>().ok(),
+                "eyr" => expiration_year = val.parse::<u32>().ok(),
+                "hgt" => height = Height::parse(val),
+                "hcl" => {
+                    hair_color = if is_valid_rgb(val) {
+                        Some(val.to_owned())
+                    } else {
+                        None
+                    }
+                }
+                "ecl" => {
+                    eye_color = if is_valid_eye_color(val) {
+                        Some(val.to_owned())
+                    } else {
+                        None
+                    }
+                }
+                "pid" => {
+                    passport_id = if is_valid_passport_id(val) {
+                        Some(val.to_owned())
+                    } else {
+                        None
+                    }
+                }
+                "cid" => country_id = Some(val.to_owned()),
+                _ => {}
+            }
+        }
+
+        Some(Passport {
+            birth_year: birth_year?,
+            issue_year: issue_year?,
+            expiration_year: expiration_year?,
+            height: height?,
+            hair_color: hair_color?,
+            eye_color: eye_color?,
+            passport_id: passport_id?,
+            country_id,
+        })
+    }
+}
+
+impl Height {
+    fn parse(s: &str) -> Option<Height> {
+        if let Some(cm) = s.strip_suffix("cm") {
+            cm.parse().ok().map(Height::Cm)
+        } else if let Some(inch) = s.strip_suffix("in") {
+            inch.parse().ok().map(Height::In)
+        } else {
+            None
+        }
+    }
+}
+
+fn is_valid_rgb(rgb: &str) -> bool {
+    if let Some(digits) = rgb.strip_prefix('#') {
+        let mut count = 0;
+
+        for digit in digits.chars() {
+            match digit {
+                '0'..='9' | 'a'..='f' | 'A'..='F' => count += 1,
+                _ => return false,
+            }
+        }
+
+        count == 6
+    } else {
+        false
+    }
+}
+
+fn is_valid_eye_color(color: &str) -> bool {
+    let valid_colors = &["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+    valid_colors.contains(&color)
+}
+
+fn is_valid_passport_id(id: &str) -> bool {
+    let mut count = 0;
+
+    for digit in id.chars() {
+        if digit.is_ascii_digit() {
+            count += 1;
+        } else {
+            return false;
+        }
+    }
+
+    count == 9
+}
+
+fn parse_batch(records: &[String]) -> Vec<Passport> {
+    records.iter().filter_map(|s| Passport::parse(s)).collect()
+}
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    /// The fields of each passport, separated by whitespace.
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, false)
+    }
+
+    /// Fields without a `:` are only rejected if `strict`.
+    fn parse_with(input: &str, strict: bool) -> Result<Self::Input> {
+        if strict {
+            let mut fields =
+                groups(input).flatten().flat_map(str::split_whitespace);
+
+            if let Some(field) = fields.find(|field| !field.contains(':')) {
+                return Err(ParseError::new(
+                    input,
+                    field,
+                    format!("Expected 'key:value', found '{}'", field),
+                )
+                .into());
+            }
+        }
+
+        Ok(groups(input).map(|group| group.join(" ")).collect())
+    }
+
+    fn part1(records: &Self::Input) -> Result<usize> {
+        let needed_fields = &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+        let count = records
+            .iter()
+            .filter(|s| {
+                let fields: Vec<_> = s
+                    .split_whitespace()
+                    .filter_map(|x| x.split(':').next())
+                    .collect();
+
+                for needed_field in needed_fields {
+                    if !fields.contains(needed_field) {
+                        return false;
+                    }
+                }
+
+                true
+            })
+            .count();
+
+        Ok(count)
+    }
+
+    fn part2(records: &Self::Input) -> Result<usize> {
+        let passports = parse_batch(records);
+
+        let count_valid = passports
+            .iter()
+            .filter(|passport| {
+                let valid_height = match passport.height {
+                    Height::Cm(cm) => (150..=193).contains(&cm),
+                    Height::In(inch) => (59..=76).contains(&inch),
+                };
+
+                valid_height
+                    && (1920..=2002).contains(&passport.birth_year)
+                    && (2010..=2020).contains(&passport.issue_year)
+                    && (2020..=2030).contains(&passport.expiration_year)
+            })
+            .count();
+
+        Ok(count_valid)
+    }
+}
+
+#[test]
+fn test_example_part1() {
+    let input = Day04::parse(
+        "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd\n\
+         byr:1937 iyr:2017 cid:147 hgt:183cm\n\
+         \n\
+         iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884\n\
+         hcl:#cfa07d byr:1929\n\
+         \n\
+         hcl:#ae17e1 iyr:2013\n\
+         eyr:2024\n\
+         ecl:brn pid:760753108 byr:1931\n\
+         hgt:179cm\n\
+         \n\
+         hcl:#cfa07d eyr:2025 pid:166559648\n\
+         iyr:2011 ecl:brn hgt:59in\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day04::part1(&input).unwrap(), 2);
+}
+
+#[test]
+fn test_example_part2() {
+    let invalid = Day04::parse(
+        "eyr:1972 cid:100\n\
+         hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926\n\
+         \n\
+         iyr:2019\n\
+         hcl:#602927 eyr:1967 hgt:170cm\n\
+         ecl:grn pid:012533040 byr:1946\n\
+         \n\
+         hcl:dab227 iyr:2012\n\
+         ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277\n\
+         \n\
+         hgt:59cm ecl:zzz\n\
+         eyr:2038 hcl:74454a iyr:2023\n\
+         pid:3556412378 byr:2007\n",
+    )
+    .unwrap();
+
+    let valid = Day04::parse(
+        "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980\n\
+         hcl:#623a2f\n\
+         \n\
+         eyr:2029 ecl:blu cid:129 byr:1989\n\
+         iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm\n\
+         \n\
+         hcl:#888785\n\
+         hgt:164cm byr:2001 iyr:2015 cid:88\n\
+         pid:545766238 ecl:hzl\n\
+         eyr:2022\n\
+         \n\
+         iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day04::part2(&invalid).unwrap(), 0);
+    assert_eq!(Day04::part2(&valid).unwrap(), 4);
+}
+
+#[test]
+fn test_strict() {
+    let input = "byr:1937 iyr:2017\n\
+                 \n\
+                 ecl:gry 860033327\n";
+
+    assert_eq!(Day04::parse(input).unwrap().len(), 2);
+
+    let err = Day04::parse_with(input, true)
+        .unwrap_err()
+        .downcast::<ParseError>()
+        .unwrap();
+    let loc = err.location.unwrap();
+    assert_eq!((loc.line, loc.column, loc.len), (3, 9, 9));
+    assert_eq!(err.message, "Expected 'key:value', found '860033327'");
+}
@@ -0,0 +1,138 @@
+use anyhow::{bail, Context, Result};
+
+use crate::parse::filter_lines;
+use crate::Solution;
+
+pub struct Day05;
+
+#[derive(Debug)]
+pub struct Seat {
+    row: usize,
+    col: usize,
+    id: u32,
+}
+
+impl Seat {
+    fn from_pos(row: usize, col: usize) -> Seat {
+        Seat {
+            row,
+            col,
+            id: (row * 8 + col) as u32,
+        }
+    }
+
+    fn parse(s: &str) -> Result<Seat> {
+        let mut min_row = 0;
+        let mut max_row = 127;
+        let mut min_col = 0;
+        let mut max_col = 7;
+
+        for c in s.chars() {
+            match c {
+                'F' => {
+                    max_row -=
+                        ((max_row - min_row) as f32 / 2f32).round() as usize
+                }
+                'B' => {
+                    min_row +=
+                        ((max_row - min_row) as f32 / 2f32).round() as usize
+                }
+                'L' => {
+                    max_col -=
+                        ((max_col - min_col) as f32 / 2f32).round() as usize
+                }
+                'R' => {
+                    min_col +=
+                        ((max_col - min_col) as f32 / 2f32).round() as usize
+                }
+                _ => bail!("Invalid seat character '{}'", c),
+            }
+        }
+
+        if min_col != max_col || min_row != max_row {
+            bail!("Incomplete seat");
+        }
+
+        Ok(Seat::from_pos(max_row, max_col))
+    }
+}
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Input = Vec<Seat>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, false)
+    }
+
+    fn parse_with(input: &str, strict: bool) -> Result<Self::Input> {
+        filter_lines(input, strict, Seat::parse)
+    }
+
+    fn part1(seats: &Self::Input) -> Result<u32> {
+        Ok(seats.iter().map(|seat| seat.id).max().unwrap_or(0))
+    }
+
+    fn part2(seats: &Self::Input) -> Result<u32> {
+        let mut reserved_seats = [[false; 8]; 128];
+
+        for seat in seats {
+            reserved_seats[seat.row][seat.col] = true;
+        }
+
+        let mut first_reserved_found = false;
+        let mut found_seat = None;
+
+        'outer: for (row, cols) in reserved_seats.iter().enumerate() {
+            for (col, &reserved) in cols.iter().enumerate() {
+                if first_reserved_found && !reserved {
+                    found_seat = Some(Seat::from_pos(row, col));
+                    break 'outer;
+                }
+
+                if !first_reserved_found && reserved {
+                    first_reserved_found = true;
+                }
+            }
+        }
+
+        let found_seat = found_seat.context("Seat not found")?;
+
+        Ok(found_seat.id)
+    }
+}
+
+#[test]
+fn test_example() {
+    let seats = [
+        ("FBFBBFFRLR", 44, 5, 357),
+        ("BFFFBBFRRR", 70, 7, 567),
+        ("FFFBBBFRRR", 14, 7, 119),
+        ("BBFFBBFRLL", 102, 4, 820),
+    ];
+
+    for &(pass, row, col, id) in &seats {
+        let seat = Seat::parse(pass).unwrap();
+        assert_eq!((seat.row, seat.col, seat.id), (row, col, id));
+    }
+
+    let input = Day05::parse(
+        &seats.iter().map(|s| format!("{}\n", s.0)).collect::<String>(),
+    )
+    .unwrap();
+    assert_eq!(Day05::part1(&input).unwrap(), 820);
+}
+
+#[test]
+fn test_part2() {
+    // Every seat of rows 1 and 2 is taken, except seat 13.
+    let input: Vec<_> = (8..24)
+        .filter(|&id| id != 13)
+        .map(|id| Seat::from_pos(id / 8, id % 8))
+        .collect();
+
+    assert_eq!(Day05::part2(&input).unwrap(), 13);
+}
@@ -0,0 +1,103 @@
+use anyhow::{bail, Result};
+
+use crate::{groups, ParseError, Solution};
+
+pub struct Day06;
+
+fn parse_answers(s: &str, answers: &mut [usize]) -> Result<()> {
+    for c in s.chars() {
+        match c {
+            'a'..='z' => {
+                let n = (c as u8 - b'a') as usize;
+                answers[n] += 1;
+            }
+            _ => bail!("Invalid answer '{}'", c),
+        }
+    }
+
+    Ok(())
+}
+
+fn count_answers(group: &[String]) -> Result<[usize; 26]> {
+    let mut answers = [0; 26];
+
+    for person in group {
+        parse_answers(person, &mut answers[..])?;
+    }
+
+    Ok(answers)
+}
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    /// The answers of each person, grouped.
+    type Input = Vec<Vec<String>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        groups(input)
+            .map(|group| {
+                group
+                    .into_iter()
+                    .map(|person| {
+                        parse_answers(person, &mut [0; 26])
+                            .map_err(|e| ParseError::new(input, person, e))?;
+                        Ok(person.to_owned())
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(groups: &Self::Input) -> Result<usize> {
+        let mut sum = 0;
+
+        for group in groups {
+            let answers = count_answers(group)?;
+            sum += answers.iter().filter(|&&x| x > 0).count();
+        }
+
+        Ok(sum)
+    }
+
+    fn part2(groups: &Self::Input) -> Result<usize> {
+        let mut sum = 0;
+
+        for group in groups {
+            let answers = count_answers(group)?;
+            sum += answers.iter().filter(|&&x| x == group.len()).count();
+        }
+
+        Ok(sum)
+    }
+}
+
+#[test]
+fn test_example() {
+    let text = "abc\n\
+                \n\
+                a\n\
+                b\n\
+                c\n\
+                \n\
+                ab\n\
+                ac\n\
+                \n\
+                a\n\
+                a\n\
+                a\n\
+                a\n\
+                \n\
+                b\n";
+
+    let input = Day06::parse(text).unwrap();
+    assert_eq!(Day06::part1(&input).unwrap(), 11);
+    assert_eq!(Day06::part2(&input).unwrap(), 6);
+
+    // CRLF line endings, trailing whitespace and extra blank lines must not
+    // change the groups.
+    let text = text.replace('\n', " \r\n").replace("\r\n \r\n", "\r\n\r\n\r\n");
+    assert_eq!(Day06::parse(&text).unwrap(), input);
+}
@@ -0,0 +1,131 @@
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+use crate::parse::filter_lines;
+use crate::Solution;
+
+pub struct Day07;
+
+#[derive(Debug)]
+pub struct Bags {
+    bags: HashMap<String, Vec<(u32, String)>>,
+}
+
+impl Bags {
+    /// Parses the bag rules, skipping malformed ones unless `strict`.
+    pub fn parse(input: &str, strict: bool) -> Result<Self> {
+        static RE_BAG: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^([a-z]+ [a-z]+) bags contain (.+)").unwrap()
+        });
+
+        static RE_INNER_BAGS: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"([0-9]+) (\w+ \w+) bags?").unwrap());
+
+        let bags = filter_lines(input, strict, |ln| {
+            let cap = RE_BAG
+                .captures(ln)
+                .context("Expected '<color> bags contain <bags>'")?;
+            let bag = &cap[1];
+
+            let inner_bags = RE_INNER_BAGS
+                .captures_iter(&cap[2])
+                .map(|cap| {
+                    let num: u32 = cap[1].parse().unwrap();
+                    let bag = &cap[2];
+                    (num, bag.to_owned())
+                })
+                .collect();
+
+            Ok((bag.to_owned(), inner_bags))
+        })?
+        .into_iter()
+        .collect();
+
+        Ok(Bags {
+            bags,
+        })
+    }
+
+    pub fn can_hold(&self, bag: &str) -> HashSet<&str> {
+        let mut set = HashSet::new();
+        self.recursive_can_hold(bag, &mut set);
+        set
+    }
+
+    fn recursive_can_hold<'a>(&'a self, bag: &str, set: &mut HashSet<&'a str>) {
+        for (b, inner_b) in self.bags.iter() {
+            if inner_b.iter().any(|x| x.1 == bag) && set.insert(b) {
+                self.recursive_can_hold(b, set);
+            }
+        }
+    }
+
+    pub fn count_inner_bags_of(&self, bag: &str) -> u32 {
+        self.bags
+            .get(bag)
+            .map(|inner_bags| {
+                inner_bags.iter().fold(0, |acc, (num, bag)| {
+                    acc + num + num * self.count_inner_bags_of(bag)
+                })
+            })
+            .unwrap_or(0)
+    }
+}
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    type Input = Bags;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Bags::parse(input, false)
+    }
+
+    fn parse_with(input: &str, strict: bool) -> Result<Self::Input> {
+        Bags::parse(input, strict)
+    }
+
+    fn part1(bags: &Bags) -> Result<usize> {
+        Ok(bags.can_hold("shiny gold").len())
+    }
+
+    fn part2(bags: &Bags) -> Result<u32> {
+        Ok(bags.count_inner_bags_of("shiny gold"))
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = Day07::parse(
+        "light red bags contain 1 bright white bag, 2 muted yellow bags.\n\
+         dark orange bags contain 3 bright white bags, 4 muted yellow bags.\n\
+         bright white bags contain 1 shiny gold bag.\n\
+         muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.\n\
+         shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.\n\
+         dark olive bags contain 3 faded blue bags, 4 dotted black bags.\n\
+         vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.\n\
+         faded blue bags contain no other bags.\n\
+         dotted black bags contain no other bags.\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day07::part1(&input).unwrap(), 4);
+    assert_eq!(Day07::part2(&input).unwrap(), 32);
+
+    let input = Day07::parse(
+        "shiny gold bags contain 2 dark red bags.\n\
+         dark red bags contain 2 dark orange bags.\n\
+         dark orange bags contain 2 dark yellow bags.\n\
+         dark yellow bags contain 2 dark green bags.\n\
+         dark green bags contain 2 dark blue bags.\n\
+         dark blue bags contain 2 dark violet bags.\n\
+         dark violet bags contain no other bags.\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day07::part2(&input).unwrap(), 126);
+}
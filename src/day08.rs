@@ -0,0 +1,150 @@
+use anyhow::{bail, Context, Result};
+use std::convert::TryFrom;
+
+use crate::parse::{parse_lines, parse_span, ParseError};
+use crate::Solution;
+
+pub struct Day08;
+
+#[derive(Debug, Clone)]
+pub enum Insn {
+    Acc(i32),
+    Jmp(i32),
+    Nop(i32),
+}
+
+pub enum Exit {
+    Success(i32),
+    InfiniteLoop(i32),
+}
+
+pub fn assemble(input: &str) -> Result<Vec<Insn>> {
+    parse_lines(input, |ln| {
+        let mut split = ln.splitn(2, ' ');
+
+        let insn = split.next().context("Instruction not found")?;
+        let num =
+            parse_span(input, split.next().context("Operand not found")?)?;
+
+        match insn {
+            "acc" => Ok(Insn::Acc(num)),
+            "jmp" => Ok(Insn::Jmp(num)),
+            "nop" => Ok(Insn::Nop(num)),
+            _ => Err(ParseError::new(
+                input,
+                insn,
+                format!("Invalid instruction '{}'", insn),
+            )
+            .into()),
+        }
+    })
+}
+
+pub fn run_code(code: &[Insn]) -> Result<Exit> {
+    if code.is_empty() {
+        bail!("Empty program");
+    }
+
+    let mut acc = 0;
+    let mut pc = 0;
+    let mut executed_insns = vec![false; code.len()];
+
+    while !executed_insns[pc] {
+        executed_insns[pc] = true;
+
+        match code[pc] {
+            Insn::Acc(x) => {
+                acc += x;
+                pc = pc.checked_add(1).context("Integer overflow")?;
+            }
+            Insn::Jmp(x) if x >= 0 => {
+                let x = usize::try_from(x).context("Integer overflow")?;
+                pc = pc.checked_add(x).context("Integer overflow")?;
+            }
+            Insn::Jmp(x) => {
+                let x = usize::try_from(-x).context("Integer overflow")?;
+                pc = pc.checked_sub(x).context("Integer underflow")?;
+            }
+
+            Insn::Nop(_) => {
+                pc = pc.checked_add(1).context("Integer overflow")?;
+            }
+        }
+
+        if pc == code.len() {
+            return Ok(Exit::Success(acc));
+        } else if pc > code.len() {
+            bail!("SEGFAULT");
+        }
+    }
+
+    Ok(Exit::InfiniteLoop(acc))
+}
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+
+    type Input = Vec<Insn>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        assemble(input)
+    }
+
+    fn part1(code: &Self::Input) -> Result<i32> {
+        match run_code(code)? {
+            Exit::InfiniteLoop(acc) => Ok(acc),
+            Exit::Success(_) => bail!("part1 not solved"),
+        }
+    }
+
+    fn part2(code: &Self::Input) -> Result<i32> {
+        let mut code = code.clone();
+
+        for i in 0..code.len() {
+            let prev_insn = code[i].clone();
+
+            match code[i] {
+                Insn::Acc(_) => continue,
+                Insn::Jmp(x) => code[i] = Insn::Nop(x),
+                Insn::Nop(0) => continue,
+                Insn::Nop(x) => code[i] = Insn::Jmp(x),
+            }
+
+            if let Ok(Exit::Success(acc)) = run_code(&code) {
+                return Ok(acc);
+            }
+
+            code[i] = prev_insn;
+        }
+
+        bail!("part2 not solved");
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = Day08::parse(
+        "nop +0\n\
+         acc +1\n\
+         jmp +4\n\
+         acc +3\n\
+         jmp -3\n\
+         acc -99\n\
+         acc +1\n\
+         jmp -4\n\
+         acc +6\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day08::part1(&input).unwrap(), 5);
+    assert_eq!(Day08::part2(&input).unwrap(), 8);
+}
+
+#[test]
+fn test_empty() {
+    let input = Day08::parse("").unwrap();
+
+    assert_eq!(Day08::part1(&input).unwrap_err().to_string(), "Empty program");
+}
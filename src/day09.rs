@@ -0,0 +1,107 @@
+use anyhow::{bail, Result};
+
+use crate::parse::{parse_lines, parse_span};
+use crate::Solution;
+
+pub struct Day09;
+
+const PREAMBLE_LEN: usize = 25;
+
+fn parse(input: &str) -> Result<Vec<u64>> {
+    parse_lines(input, |ln| parse_span(input, ln))
+}
+
+fn is_valid_num(preamble: &[u64], num: u64) -> bool {
+    for x in preamble {
+        for y in preamble {
+            if x == y {
+                continue;
+            }
+
+            if x + y == num {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+fn find_invalid_num(buf: &[u64], preamble_len: usize) -> Result<u64> {
+    for i in preamble_len..buf.len() {
+        let preamble = &buf[i - preamble_len..i];
+        let num = buf[i];
+
+        if !is_valid_num(preamble, num) {
+            return Ok(num);
+        }
+    }
+
+    bail!("Failed to find invalid num");
+}
+
+fn find_weakness(buf: &[u64], preamble_len: usize) -> Result<u64> {
+    let invalid_num = find_invalid_num(buf, preamble_len)?;
+
+    for i in 0..buf.len() {
+        let mut sum = buf[i];
+
+        // The run needs at least two numbers, so starts at `j = i + 1`.
+        for j in i + 1..buf.len() {
+            sum += buf[j];
+
+            if sum > invalid_num {
+                break;
+            } else if sum == invalid_num {
+                let min = buf[i..=j].iter().min().unwrap();
+                let max = buf[i..=j].iter().max().unwrap();
+
+                return Ok(min + max);
+            }
+        }
+    }
+
+    bail!("Failed to solve part2");
+}
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+
+    type Input = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(buf: &Self::Input) -> Result<u64> {
+        find_invalid_num(buf, PREAMBLE_LEN)
+    }
+
+    fn part2(buf: &Self::Input) -> Result<u64> {
+        find_weakness(buf, PREAMBLE_LEN)
+    }
+}
+
+#[test]
+fn test_example() {
+    let buf = parse(
+        "35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n150\n182\n127\n\
+         219\n299\n277\n309\n576\n",
+    )
+    .unwrap();
+
+    assert_eq!(find_invalid_num(&buf, 5).unwrap(), 127);
+    assert_eq!(find_weakness(&buf, 5).unwrap(), 62);
+}
+
+#[test]
+fn test_weakness_run() {
+    // 5 is invalid but also in the list, and the run ends with its maximum.
+    let buf = [5, 1, 4, 6, 10, 5];
+
+    assert_eq!(find_invalid_num(&buf, 3).unwrap(), 5);
+    assert_eq!(find_weakness(&buf, 3).unwrap(), 5);
+    assert!(find_weakness(&[1, 2, 4, 3], 2).is_err());
+}
@@ -0,0 +1,125 @@
+use anyhow::{bail, Result};
+
+use crate::parse::{parse_lines, parse_span};
+use crate::Solution;
+
+pub struct Day10;
+
+fn parse_joltages(input: &str) -> Result<Vec<u32>> {
+    parse_lines(input, |ln| parse_span(input, ln))
+}
+
+fn solve_part1(joltages: &[u32]) -> u32 {
+    let mut prev = 0;
+    let mut diff_1 = 0;
+    let mut diff_3 = 0;
+
+    for &joltage in joltages {
+        let diff = joltage - prev;
+
+        if diff == 1 {
+            diff_1 += 1;
+        } else if diff == 3 {
+            diff_3 += 1;
+        }
+
+        prev = joltage;
+    }
+
+    diff_1 * diff_3
+}
+
+/// Counts the chains reaching each adapter from those at most 3 jolts
+/// below it.
+fn solve_part2(joltages: &[u32]) -> u64 {
+    let mut chains: Vec<u64> = Vec::with_capacity(joltages.len());
+
+    for (i, &joltage) in joltages.iter().enumerate() {
+        let from_outlet = u64::from(joltage <= 3);
+        let from_adapters: u64 = (0..i)
+            .rev()
+            .take_while(|&j| joltage - joltages[j] <= 3)
+            .map(|j| chains[j])
+            .sum();
+
+        chains.push(from_outlet + from_adapters);
+    }
+
+    chains.last().copied().unwrap_or(0)
+}
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut joltages = parse_joltages(input)?;
+
+        joltages.sort();
+
+        if joltages.is_empty() {
+            bail!("No adapters");
+        }
+
+        let mut prev = 0;
+        for &joltage in &joltages {
+            if joltage - prev > 3 {
+                bail!("No adapter between {} and {} jolts", prev, joltage);
+            }
+            prev = joltage;
+        }
+
+        joltages.push(prev + 3);
+
+        Ok(joltages)
+    }
+
+    fn part1(joltages: &Self::Input) -> Result<u32> {
+        Ok(solve_part1(joltages))
+    }
+
+    fn part2(joltages: &Self::Input) -> Result<u64> {
+        Ok(solve_part2(joltages))
+    }
+}
+
+#[test]
+fn test_basic() {
+    assert_eq!(solve_part2(&[1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19]), 8);
+    assert_eq!(solve_part2(&[1, 4, 5, 6, 7, 10]), 4);
+    assert_eq!(solve_part2(&[1, 4, 5, 6, 7, 8, 11]), 7);
+    assert_eq!(solve_part2(&[1, 2, 3, 6]), 4);
+    assert_eq!(solve_part2(&[3, 4, 6, 7, 8, 11]), 5);
+    assert_eq!(solve_part2(&[1, 4, 5, 6, 7, 10, 11, 12, 13, 14, 17]), 28);
+    assert_eq!(
+        solve_part2(&[
+            1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28,
+            31, 32, 33, 34, 35, 38, 39, 42, 45, 46, 47, 48, 49
+        ]),
+        19208
+    );
+}
+
+#[test]
+fn test_example() {
+    let input =
+        Day10::parse("16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4\n").unwrap();
+
+    assert_eq!(Day10::part1(&input).unwrap(), 35);
+    assert_eq!(Day10::part2(&input).unwrap(), 8);
+
+    let input = Day10::parse(
+        "28\n33\n18\n42\n31\n14\n46\n20\n48\n47\n24\n23\n49\n45\n19\n38\n\
+         39\n11\n1\n32\n25\n35\n8\n17\n7\n9\n4\n2\n34\n10\n3\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day10::part1(&input).unwrap(), 220);
+    assert_eq!(Day10::part2(&input).unwrap(), 19208);
+
+    assert!(Day10::parse("").is_err());
+    assert!(Day10::parse("1\n5\n").is_err());
+}
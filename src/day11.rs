@@ -0,0 +1,204 @@
+use anyhow::{bail, Result};
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+
+use crate::grid::{Grid, DIRECTIONS8};
+use crate::visualize::Visualizer;
+use crate::{Part, Solution};
+
+pub struct Day11;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Floor,
+    EmptySeat,
+    OccupiedSeat,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Cell::Floor => '.',
+            Cell::EmptySeat => 'L',
+            Cell::OccupiedSeat => '#',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+fn parse_layout(input: &str) -> Result<Grid<Cell>> {
+    Grid::parse(input, |c| match c {
+        '.' => Some(Cell::Floor),
+        'L' => Some(Cell::EmptySeat),
+        '#' => Some(Cell::OccupiedSeat),
+        _ => None,
+    })
+}
+
+fn count_occupied_seats(
+    layout: &Grid<Cell>,
+    pos: (usize, usize),
+    limit: Option<usize>,
+) -> usize {
+    DIRECTIONS8
+        .iter()
+        .filter(|&&dir| {
+            layout
+                .ray(pos, dir)
+                .take(limit.unwrap_or(usize::MAX))
+                .map(|pos| layout[pos])
+                .find(|&cell| cell != Cell::Floor)
+                == Some(Cell::OccupiedSeat)
+        })
+        .count()
+}
+
+fn round(
+    layout: &mut Grid<Cell>,
+    tolerance: usize,
+    limit: Option<usize>,
+) -> bool {
+    let mut seat_changed = false;
+    let saved_layout = layout.clone();
+
+    for pos in saved_layout.positions() {
+        let cur_seat = saved_layout[pos];
+        let occupied_seats = count_occupied_seats(&saved_layout, pos, limit);
+
+        if cur_seat == Cell::EmptySeat && occupied_seats == 0 {
+            layout[pos] = Cell::OccupiedSeat;
+            seat_changed = true;
+        } else if cur_seat == Cell::OccupiedSeat && occupied_seats >= tolerance
+        {
+            layout[pos] = Cell::EmptySeat;
+            seat_changed = true;
+        }
+    }
+
+    seat_changed
+}
+
+/// Runs rounds until the layout stops changing. Some layouts never settle
+/// and cycle instead, which is an error.
+fn stabilize(
+    layout: &Grid<Cell>,
+    tolerance: usize,
+    limit: Option<usize>,
+) -> Result<Grid<Cell>> {
+    stabilize_with(layout, tolerance, limit, |_| Ok(()))
+}
+
+/// Like `stabilize`, calling `on_round` with the initial layout and the
+/// layout after every round.
+fn stabilize_with<F>(
+    layout: &Grid<Cell>,
+    tolerance: usize,
+    limit: Option<usize>,
+    mut on_round: F,
+) -> Result<Grid<Cell>>
+where
+    F: FnMut(&Grid<Cell>) -> Result<()>,
+{
+    let mut layout = layout.clone();
+    let mut seen = HashSet::new();
+
+    on_round(&layout)?;
+
+    while round(&mut layout, tolerance, limit) {
+        on_round(&layout)?;
+
+        if !seen.insert(layout.clone()) {
+            bail!("Seats never stabilize after {} rounds", seen.len());
+        }
+    }
+
+    Ok(layout)
+}
+
+/// Renders the layout after every round of `part`.
+pub fn visualize(
+    layout: &Grid<Cell>,
+    part: Part,
+    vis: &mut Visualizer,
+) -> Result<()> {
+    let (tolerance, limit) = rules(part);
+    let mut round = 0;
+
+    stabilize_with(layout, tolerance, limit, |layout| {
+        let title = format!(
+            "Day 11 part {}, round {}: {} occupied seats",
+            part,
+            round,
+            count_all_occupied_seats(layout)
+        );
+        round += 1;
+
+        vis.frame(&title, layout)
+    })?;
+
+    Ok(())
+}
+
+/// Occupied seats that empty a seat, and how far seats are seen, in `part`.
+fn rules(part: Part) -> (usize, Option<usize>) {
+    match part {
+        Part::One => (4, Some(1)),
+        Part::Two => (5, None),
+    }
+}
+
+fn count_all_occupied_seats(layout: &Grid<Cell>) -> usize {
+    layout.iter().filter(|&&cell| cell == Cell::OccupiedSeat).count()
+}
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Input = Grid<Cell>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_layout(input)
+    }
+
+    fn part1(layout: &Self::Input) -> Result<usize> {
+        let (tolerance, limit) = rules(Part::One);
+        let layout = stabilize(layout, tolerance, limit)?;
+
+        Ok(count_all_occupied_seats(&layout))
+    }
+
+    fn part2(layout: &Self::Input) -> Result<usize> {
+        let (tolerance, limit) = rules(Part::Two);
+        let layout = stabilize(layout, tolerance, limit)?;
+
+        Ok(count_all_occupied_seats(&layout))
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = Day11::parse(
+        "L.LL.LL.LL\n\
+         LLLLLLL.LL\n\
+         L.L.L..L..\n\
+         LLLL.LL.LL\n\
+         L.LL.LL.LL\n\
+         L.LLLLL.LL\n\
+         ..L.L.....\n\
+         LLLLLLLLLL\n\
+         L.LLLLLL.L\n\
+         L.LLLLL.LL\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day11::part1(&input).unwrap(), 37);
+    assert_eq!(Day11::part2(&input).unwrap(), 26);
+
+    // Every seat fills up, then every seat has at least 4 occupied
+    // neighbours and empties, forever.
+    let input = Day11::parse(".LL.\nLLLL\nLLLL\n.LL.\n").unwrap();
+    assert!(Day11::part1(&input).is_err());
+}
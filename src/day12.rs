@@ -0,0 +1,300 @@
+use anyhow::{bail, Result};
+
+use crate::grid::Grid;
+use crate::parse::{parse_lines, parse_span, ParseError};
+use crate::visualize::Visualizer;
+use crate::{Part, Solution};
+
+pub struct Day12;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+    Left,
+    Right,
+    Forward,
+}
+
+#[derive(Debug)]
+pub struct Action {
+    direction: Direction,
+    units: u32,
+}
+
+impl Direction {
+    fn turn_right(&mut self, degrees: u32) -> Result<()> {
+        for _ in 0..degrees / 90 {
+            match self {
+                Direction::East => *self = Direction::South,
+                Direction::South => *self = Direction::West,
+                Direction::West => *self = Direction::North,
+                Direction::North => *self = Direction::East,
+                _ => bail!("Direction can not turn right"),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn turn_left(&mut self, degrees: u32) -> Result<()> {
+        for _ in 0..degrees / 90 {
+            match self {
+                Direction::East => *self = Direction::North,
+                Direction::North => *self = Direction::West,
+                Direction::West => *self = Direction::South,
+                Direction::South => *self = Direction::East,
+                _ => bail!("Direction can not turn left"),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_actions(input: &str) -> Result<Vec<Action>> {
+    parse_lines(input, |ln| {
+        let dir_len = ln.chars().next().map_or(0, char::len_utf8);
+        let (dir, units) = ln.split_at(dir_len);
+
+        let direction = match dir {
+            "N" => Direction::North,
+            "S" => Direction::South,
+            "E" => Direction::East,
+            "W" => Direction::West,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            "F" => Direction::Forward,
+            _ => {
+                return Err(ParseError::new(
+                    input,
+                    dir,
+                    format!("Invalid direction '{}'", dir),
+                )
+                .into())
+            }
+        };
+
+        let units = parse_span(input, units)?;
+
+        Ok(Action {
+            direction,
+            units,
+        })
+    })
+}
+
+/// Position of the ship, east and north of its start.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ship {
+    pub east: i32,
+    pub north: i32,
+    /// Waypoint relative to the ship, when navigating by waypoint.
+    pub waypoint: Option<(i32, i32)>,
+}
+
+impl Ship {
+    fn distance(&self) -> i32 {
+        self.east.abs() + self.north.abs()
+    }
+}
+
+/// Moves the ship by `actions`, calling `on_step` with its starting position
+/// and its position after every action.
+fn sail<F>(actions: &[Action], mut on_step: F) -> Result<Ship>
+where
+    F: FnMut(Ship) -> Result<()>,
+{
+    let mut east_west: i32 = 0;
+    let mut north_south: i32 = 0;
+    let mut facing_dir = Direction::East;
+    let ship = |east, north| Ship {
+        east,
+        north,
+        waypoint: None,
+    };
+
+    on_step(ship(0, 0))?;
+
+    for action in actions {
+        let direction = if action.direction == Direction::Forward {
+            facing_dir
+        } else {
+            action.direction
+        };
+
+        match direction {
+            Direction::Right => facing_dir.turn_right(action.units)?,
+            Direction::Left => facing_dir.turn_left(action.units)?,
+            Direction::East => east_west += action.units as i32,
+            Direction::West => east_west -= action.units as i32,
+            Direction::North => north_south += action.units as i32,
+            Direction::South => north_south -= action.units as i32,
+            _ => unreachable!(),
+        }
+
+        on_step(ship(east_west, north_south))?;
+    }
+
+    Ok(ship(east_west, north_south))
+}
+
+/// Like `sail`, but the actions move a waypoint that the ship moves to.
+fn sail_to_waypoint<F>(actions: &[Action], mut on_step: F) -> Result<Ship>
+where
+    F: FnMut(Ship) -> Result<()>,
+{
+    let mut east_west_waypoint: i32 = 10;
+    let mut north_south_waypoint: i32 = 1;
+    let mut east_west: i32 = 0;
+    let mut north_south: i32 = 0;
+    let ship = |east, north, waypoint| Ship {
+        east,
+        north,
+        waypoint: Some(waypoint),
+    };
+
+    on_step(ship(0, 0, (east_west_waypoint, north_south_waypoint)))?;
+
+    for action in actions {
+        match action.direction {
+            Direction::Forward => {
+                east_west += east_west_waypoint * action.units as i32;
+                north_south += north_south_waypoint * action.units as i32;
+            }
+            Direction::Right => {
+                for _ in 0..action.units / 90 {
+                    let new_ew = north_south_waypoint;
+                    north_south_waypoint = -east_west_waypoint;
+                    east_west_waypoint = new_ew;
+                }
+            }
+            Direction::Left => {
+                for _ in 0..action.units / 90 {
+                    let new_ns = east_west_waypoint;
+                    east_west_waypoint = -north_south_waypoint;
+                    north_south_waypoint = new_ns;
+                }
+            }
+            Direction::East => east_west_waypoint += action.units as i32,
+            Direction::West => east_west_waypoint -= action.units as i32,
+            Direction::North => north_south_waypoint += action.units as i32,
+            Direction::South => north_south_waypoint -= action.units as i32,
+        }
+
+        on_step(ship(
+            east_west,
+            north_south,
+            (east_west_waypoint, north_south_waypoint),
+        ))?;
+    }
+
+    Ok(ship(east_west, north_south, (east_west_waypoint, north_south_waypoint)))
+}
+
+const CANVAS_WIDTH: usize = 72;
+const CANVAS_HEIGHT: usize = 24;
+
+/// Renders the route of the ship after every action of `part`, scaled to
+/// fit the canvas: `o` is the start, `@` the ship, `.` its route so far and
+/// `+` its waypoint.
+pub fn visualize(
+    actions: &[Action],
+    part: Part,
+    vis: &mut Visualizer,
+) -> Result<()> {
+    let mut ships = Vec::new();
+    let push = |ship| {
+        ships.push(ship);
+        Ok(())
+    };
+
+    match part {
+        Part::One => sail(actions, push)?,
+        Part::Two => sail_to_waypoint(actions, push)?,
+    };
+
+    let points = |ship: &Ship| {
+        let waypoint =
+            ship.waypoint.map(|(e, n)| (ship.east + e, ship.north + n));
+        std::iter::once((ship.east, ship.north)).chain(waypoint)
+    };
+    let (mut min_e, mut max_e, mut min_n, mut max_n) = (0, 0, 0, 0);
+
+    for (e, n) in ships.iter().flat_map(points) {
+        min_e = min_e.min(e);
+        max_e = max_e.max(e);
+        min_n = min_n.min(n);
+        max_n = max_n.max(n);
+    }
+
+    // North is up.
+    let scale = |(e, n): (i32, i32)| {
+        let scale = |v: i32, min: i32, max: i32, len: usize| {
+            (v - min) as i64 * (len as i64 - 1) / (max - min).max(1) as i64
+        };
+
+        (
+            scale(e, min_e, max_e, CANVAS_WIDTH) as usize,
+            CANVAS_HEIGHT - 1 - scale(n, min_n, max_n, CANVAS_HEIGHT) as usize,
+        )
+    };
+
+    let mut canvas =
+        Grid::new(CANVAS_WIDTH, vec![' '; CANVAS_WIDTH * CANVAS_HEIGHT])?;
+
+    for (step, ship) in ships.iter().enumerate() {
+        let mut frame = canvas.clone();
+
+        frame[scale((0, 0))] = 'o';
+        if let Some((e, n)) = ship.waypoint {
+            frame[scale((ship.east + e, ship.north + n))] = '+';
+        }
+        frame[scale((ship.east, ship.north))] = '@';
+
+        let title = format!(
+            "Day 12 part {}, action {}/{}: east {}, north {}, distance {}",
+            part,
+            step,
+            actions.len(),
+            ship.east,
+            ship.north,
+            ship.distance()
+        );
+        vis.frame(&title, &frame)?;
+
+        canvas[scale((ship.east, ship.north))] = '.';
+    }
+
+    Ok(())
+}
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Vec<Action>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_actions(input)
+    }
+
+    fn part1(actions: &Self::Input) -> Result<i32> {
+        Ok(sail(actions, |_| Ok(()))?.distance())
+    }
+
+    fn part2(actions: &Self::Input) -> Result<i32> {
+        Ok(sail_to_waypoint(actions, |_| Ok(()))?.distance())
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = Day12::parse("F10\nN3\nF7\nR90\nF11\n").unwrap();
+
+    assert_eq!(Day12::part1(&input).unwrap(), 25);
+    assert_eq!(Day12::part2(&input).unwrap(), 286);
+}
@@ -0,0 +1,75 @@
+use anyhow::{bail, Context, Result};
+
+use crate::parse::parse_span;
+use crate::Solution;
+
+pub struct Day13;
+
+/// Parses the arrival time and buses, skipping malformed buses unless
+/// `strict`.
+fn parse(input: &str, strict: bool) -> Result<(u32, Vec<u32>)> {
+    let mut lines = input.lines();
+
+    let arrived_tm =
+        parse_span(input, lines.next().context("Missing arrival time")?)?;
+
+    // Out of service buses are marked with `x`.
+    let buses = lines
+        .next()
+        .context("Missing buses")?
+        .split(',')
+        .filter(|&n| n != "x")
+        .map(|n| parse_span(input, n))
+        .filter(|res| strict || res.is_ok())
+        .collect::<Result<Vec<_>>>()?;
+
+    if buses.is_empty() {
+        bail!("No buses in service");
+    }
+
+    Ok((arrived_tm, buses))
+}
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Input = (u32, Vec<u32>);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input, false)
+    }
+
+    fn parse_with(input: &str, strict: bool) -> Result<Self::Input> {
+        parse(input, strict)
+    }
+
+    fn part1((arrived_tm, buses): &Self::Input) -> Result<u32> {
+        let arrived_tm = *arrived_tm;
+        let mut closed_bus = buses[0];
+        let mut closed_tm = arrived_tm + (buses[0] - arrived_tm % buses[0]);
+
+        for bus in buses.iter().skip(1) {
+            let tm = arrived_tm + (bus - arrived_tm % bus);
+
+            if tm < closed_tm {
+                closed_bus = *bus;
+                closed_tm = tm;
+            }
+        }
+
+        Ok((closed_tm - arrived_tm) * closed_bus)
+    }
+
+    fn part2(_: &Self::Input) -> Result<u32> {
+        bail!("part2 not solved");
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = Day13::parse("939\n7,13,x,x,59,x,31,19\n").unwrap();
+
+    assert_eq!(Day13::part1(&input).unwrap(), 295);
+}
@@ -0,0 +1,155 @@
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+
+use crate::parse::{parse_lines, parse_span, ParseError};
+use crate::Solution;
+
+pub struct Day14;
+
+pub enum Insn {
+    Mask(u64, u64),
+    Mem(u64, u64),
+}
+
+fn parse_mask(input: &str, s: &str) -> Result<(u64, u64)> {
+    let mut x_bits = 0;
+    let mut set_bits = 0;
+
+    if s.len() != 36 {
+        bail!("Expected a mask of 36 bits");
+    }
+
+    for (i, (pos, c)) in s.char_indices().rev().enumerate() {
+        match c {
+            'X' => x_bits |= 1 << i,
+            '1' => set_bits |= 1 << i,
+            '0' => {}
+            _ => {
+                let span = &s[pos..pos + c.len_utf8()];
+                let msg = format!("Invalid mask bit '{}'", c);
+                return Err(ParseError::new(input, span, msg).into());
+            }
+        }
+    }
+
+    Ok((x_bits, set_bits))
+}
+
+fn parse(input: &str) -> Result<Vec<Insn>> {
+    parse_lines(input, |ln| {
+        let (k, v) = ln.split_once(" = ").context("Expected 'KEY = VALUE'")?;
+
+        if k == "mask" {
+            let (x, set) = parse_mask(input, v)?;
+            Ok(Insn::Mask(x, set))
+        } else if let Some(k) =
+            k.strip_prefix("mem[").and_then(|k| k.strip_suffix(']'))
+        {
+            Ok(Insn::Mem(parse_span(input, k)?, parse_span(input, v)?))
+        } else {
+            let msg = format!("Invalid instruction '{}'", k);
+            Err(ParseError::new(input, k, msg).into())
+        }
+    })
+}
+
+fn ones_pos(val: u64) -> Vec<u64> {
+    let mut pos = Vec::new();
+
+    for i in 0..64 {
+        if val & (1 << i) != 0 {
+            pos.push(i);
+        }
+    }
+
+    pos
+}
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type Input = Vec<Insn>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(insns: &Self::Input) -> Result<u64> {
+        let mut keep_bits = !0;
+        let mut or_bits = 0;
+        let mut mem = HashMap::new();
+
+        for insn in insns {
+            match *insn {
+                Insn::Mask(x, set) => {
+                    keep_bits = x;
+                    or_bits = set;
+                }
+                Insn::Mem(addr, mut val) => {
+                    val &= keep_bits;
+                    val |= or_bits;
+                    mem.insert(addr, val);
+                }
+            }
+        }
+
+        Ok(mem.values().sum())
+    }
+
+    fn part2(insns: &Self::Input) -> Result<u64> {
+        let mut mem = HashMap::new();
+        let mut x_bits_pos = Vec::new();
+        let mut set_bits = 0;
+
+        for insn in insns {
+            match *insn {
+                Insn::Mask(x, set) => {
+                    x_bits_pos = ones_pos(x);
+                    set_bits = set;
+                }
+                Insn::Mem(mut addr, val) => {
+                    addr |= set_bits;
+
+                    for bits in 0..(1 << x_bits_pos.len()) {
+                        for (i, pos) in x_bits_pos.iter().enumerate() {
+                            if bits & (1 << i) != 0 {
+                                addr |= 1 << pos;
+                            } else {
+                                addr &= !(1 << pos);
+                            }
+                        }
+
+                        mem.insert(addr, val);
+                    }
+                }
+            }
+        }
+
+        Ok(mem.values().sum())
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = Day14::parse(
+        "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\n\
+         mem[8] = 11\n\
+         mem[7] = 101\n\
+         mem[8] = 0\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day14::part1(&input).unwrap(), 165);
+
+    let input = Day14::parse(
+        "mask = 000000000000000000000000000000X1001X\n\
+         mem[42] = 100\n\
+         mask = 00000000000000000000000000000000X0XX\n\
+         mem[26] = 1\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day14::part2(&input).unwrap(), 208);
+}
@@ -0,0 +1,77 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+use crate::parse::parse_span;
+use crate::Solution;
+
+pub struct Day15;
+
+fn parse(input: &str) -> Result<Vec<u32>> {
+    input.trim().split(',').map(|n| parse_span(input, n)).collect()
+}
+
+pub fn start_game(input: &[u32], last_turn: u32) -> u32 {
+    let mut prev_num = input[input.len() - 1];
+    let mut spoken_nums: HashMap<_, _> =
+        input.iter().enumerate().map(|(i, &k)| (k, i as u32 + 1)).collect();
+    let start_turn = (spoken_nums.len() + 1) as u32;
+
+    for turn in start_turn..=last_turn {
+        let num = if !spoken_nums.contains_key(&prev_num) {
+            0
+        } else {
+            turn - 1 - spoken_nums[&prev_num]
+        };
+
+        spoken_nums.insert(prev_num, turn - 1);
+        prev_num = num;
+    }
+
+    prev_num
+}
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        Ok(start_game(input, 2020))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32> {
+        Ok(start_game(input, 30000000))
+    }
+}
+
+#[test]
+fn test_example() {
+    let games = [
+        ("0,3,6", 436),
+        ("1,3,2", 1),
+        ("2,1,3", 10),
+        ("1,2,3", 27),
+        ("2,3,1", 78),
+        ("3,2,1", 438),
+        ("3,1,2", 1836),
+    ];
+
+    for &(start, spoken) in &games {
+        let input = Day15::parse(start).unwrap();
+        assert_eq!(Day15::part1(&input).unwrap(), spoken);
+    }
+}
+
+// Takes a while without optimizations, run it with `--release --ignored`.
+#[test]
+#[ignore]
+fn test_example_part2() {
+    let input = Day15::parse("0,3,6").unwrap();
+    assert_eq!(Day15::part2(&input).unwrap(), 175594);
+}
@@ -0,0 +1,301 @@
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::iter;
+use std::ops::RangeInclusive;
+
+use crate::parse::parse_span;
+use crate::{groups, ParseError, Solution};
+
+pub struct Day16;
+
+#[derive(Clone)]
+pub struct Data {
+    rules: HashMap<String, RuleRange>,
+    my_ticket: Vec<u32>,
+    nearby_tickets: Vec<Vec<u32>>,
+}
+
+#[derive(Clone)]
+struct RuleRange {
+    range1: RangeInclusive<u32>,
+    range2: RangeInclusive<u32>,
+}
+
+impl RuleRange {
+    fn contains(&self, item: u32) -> bool {
+        self.range1.contains(&item) || self.range2.contains(&item)
+    }
+}
+
+impl Data {
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut sections = groups(input);
+        let mut rules = HashMap::new();
+
+        for ln in sections.next().context("Missing rules")? {
+            let rule_err =
+                || ParseError::new(input, ln, "Expected 'NAME: A-B or C-D'");
+            let (name, rule) = ln.split_once(": ").ok_or_else(rule_err)?;
+            let ((range1_start, range1_end), (range2_start, range2_end)) =
+                parse_rule(rule).ok_or_else(rule_err)?;
+
+            rules.insert(
+                name.to_owned(),
+                RuleRange {
+                    range1: range1_start..=range1_end,
+                    range2: range2_start..=range2_end,
+                },
+            );
+        }
+
+        let my_ticket = match section(sections.next(), "your ticket:")?[..] {
+            [ln] => parse_ticket(input, ln)?,
+            _ => bail!("Expected exactly one ticket of mine"),
+        };
+
+        let nearby_tickets = section(sections.next(), "nearby tickets:")?
+            .iter()
+            .map(|ln| {
+                let ticket = parse_ticket(input, ln)?;
+
+                if ticket.len() != my_ticket.len() {
+                    let msg = format!(
+                        "Expected {} fields like my ticket, found {}",
+                        my_ticket.len(),
+                        ticket.len()
+                    );
+                    return Err(ParseError::new(input, ln, msg).into());
+                }
+
+                Ok(ticket)
+            })
+            .collect::<Result<_>>()?;
+
+        if sections.next().is_some() {
+            bail!("Unexpected input after nearby tickets");
+        }
+
+        Ok(Data {
+            rules,
+            my_ticket,
+            nearby_tickets,
+        })
+    }
+
+    fn field_is_valid(&self, field: u32) -> bool {
+        for rule in self.rules.values() {
+            if rule.contains(field) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn discard_invalid_tickets(&mut self) {
+        let mut i = 0;
+
+        'outer: while i < self.nearby_tickets.len() {
+            for j in 0..self.nearby_tickets[i].len() {
+                let field = self.nearby_tickets[i][j];
+
+                if !self.field_is_valid(field) {
+                    self.nearby_tickets.remove(i);
+                    continue 'outer;
+                }
+            }
+
+            i += 1;
+        }
+    }
+
+    fn field_positions(&self) -> Result<HashMap<String, usize>> {
+        let mut positions = HashMap::new();
+
+        while positions.len() < self.rules.len() {
+            let assigned = positions.len();
+
+            for (name, rule) in self.rules.iter() {
+                if positions.contains_key(name) {
+                    continue;
+                }
+
+                let mut fields_count = vec![0; self.my_ticket.len()];
+
+                for ticket in iter::once(&self.my_ticket)
+                    .chain(self.nearby_tickets.iter())
+                {
+                    for (i, field) in ticket.iter().enumerate() {
+                        if positions.values().any(|pos| *pos == i) {
+                            continue;
+                        }
+
+                        if rule.contains(*field) {
+                            fields_count[i] += 1;
+                        }
+                    }
+                }
+
+                let (pos, max) = fields_count
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|x| x.1)
+                    .unwrap();
+                let max_count =
+                    fields_count.iter().filter(|x| *x == max).count();
+
+                if max_count == 1 {
+                    positions.insert(name.to_owned(), pos);
+                }
+            }
+
+            if positions.len() == assigned {
+                bail!(
+                    "Ambiguous positions of {} fields",
+                    self.rules.len() - assigned
+                );
+            }
+        }
+
+        Ok(positions)
+    }
+}
+
+fn parse_rule(rule: &str) -> Option<((u32, u32), (u32, u32))> {
+    let mut split = rule.split(" or ");
+
+    let mut rule1 = split.next()?.split('-');
+    let rule1_start = rule1.next()?.parse().ok()?;
+    let rule1_end = rule1.next()?.parse().ok()?;
+
+    let mut rule2 = split.next()?.split('-');
+    let rule2_start = rule2.next()?.parse().ok()?;
+    let rule2_end = rule2.next()?.parse().ok()?;
+
+    Some(((rule1_start, rule1_end), (rule2_start, rule2_end)))
+}
+
+/// Returns the lines of `group` after its `header`.
+fn section<'a>(
+    group: Option<Vec<&'a str>>,
+    header: &str,
+) -> Result<Vec<&'a str>> {
+    match group {
+        Some(mut lines) if lines[0] == header => {
+            lines.remove(0);
+            Ok(lines)
+        }
+        _ => bail!("Missing '{}' section", header),
+    }
+}
+
+fn parse_ticket(input: &str, s: &str) -> Result<Vec<u32>> {
+    s.split(',').map(|n| parse_span(input, n)).collect()
+}
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    type Input = Data;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Data::parse(input)
+    }
+
+    fn part1(data: &Data) -> Result<u32> {
+        let mut error_rate = 0;
+
+        for ticket in &data.nearby_tickets {
+            for field in ticket {
+                if !data.field_is_valid(*field) {
+                    error_rate += field;
+                }
+            }
+        }
+
+        Ok(error_rate)
+    }
+
+    fn part2(data: &Data) -> Result<u64> {
+        let mut data = data.clone();
+        let mut product: u64 = 1;
+
+        data.discard_invalid_tickets();
+        let positions = data.field_positions()?;
+
+        for (name, pos) in positions {
+            if name.starts_with("departure") {
+                product *= data.my_ticket[pos] as u64;
+            }
+        }
+
+        Ok(product)
+    }
+}
+
+#[test]
+fn test_example() {
+    let input = Day16::parse(
+        "class: 1-3 or 5-7\n\
+         row: 6-11 or 33-44\n\
+         seat: 13-40 or 45-50\n\
+         \n\
+         your ticket:\n\
+         7,1,14\n\
+         \n\
+         nearby tickets:\n\
+         7,3,47\n\
+         40,4,50\n\
+         55,2,20\n\
+         38,6,12\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day16::part1(&input).unwrap(), 71);
+
+    let mut input = Day16::parse(
+        "class: 0-1 or 4-19\n\
+         row: 0-5 or 8-19\n\
+         seat: 0-13 or 16-19\n\
+         \n\
+         your ticket:\n\
+         11,12,13\n\
+         \n\
+         nearby tickets:\n\
+         3,9,18\n\
+         15,1,5\n\
+         5,14,9\n",
+    )
+    .unwrap();
+
+    input.discard_invalid_tickets();
+    let positions = input.field_positions().unwrap();
+    assert_eq!(positions["class"], 1);
+    assert_eq!(positions["row"], 0);
+    assert_eq!(positions["seat"], 2);
+}
+
+#[test]
+fn test_ticket_length() {
+    let input = "class: 1-3 or 5-7\n\
+                 row: 6-11 or 33-44\n\
+                 \n\
+                 your ticket:\n\
+                 7,1\n\
+                 \n\
+                 nearby tickets:\n\
+                 7,3\n\
+                 40,4,50\n";
+
+    let err = Day16::parse(input)
+        .err()
+        .and_then(|e| e.downcast::<ParseError>().ok())
+        .unwrap();
+
+    let loc = err.location.unwrap();
+    assert_eq!((loc.line, loc.column), (9, 1));
+    assert_eq!(loc.text, "40,4,50");
+    assert_eq!(err.message, "Expected 2 fields like my ticket, found 3");
+}
@@ -0,0 +1,733 @@
+//! Random, valid inputs for every day.
+//!
+//! The same seed always generates the same input. `size` roughly is the
+//! number of lines, or items, of the generated input.
+
+use anyhow::{bail, Result};
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+use crate::day11::Day11;
+use crate::Solution;
+
+/// Small, deterministic pseudo random generator (splitmix64).
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `lo..=hi`.
+    pub fn range(&mut self, lo: u64, hi: u64) -> u64 {
+        assert!(lo <= hi, "empty range {}..={}", lo, hi);
+
+        match (hi - lo).checked_add(1) {
+            Some(len) => lo + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    /// Uniform index of a slice of `len` items.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0, len as u64 - 1) as usize
+    }
+
+    /// True with probability `num / den`.
+    pub fn ratio(&mut self, num: u64, den: u64) -> bool {
+        self.range(1, den) <= num
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// Days with a generator.
+pub const DAYS: [u32; 16] =
+    [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+
+/// Generates an input for `day`.
+pub fn generate(day: u32, rng: &mut Rng, size: usize) -> Result<String> {
+    let size = size.max(1);
+
+    let input = match day {
+        1 => day01(rng, size),
+        2 => day02(rng, size),
+        3 => day03(rng, size),
+        4 => day04(rng, size),
+        5 => day05(rng, size),
+        6 => day06(rng, size),
+        7 => day07(rng, size),
+        8 => day08(rng, size),
+        9 => day09(rng, size),
+        10 => day10(rng, size),
+        11 => day11(rng, size),
+        12 => day12(rng, size),
+        13 => day13(rng, size),
+        14 => day14(rng, size),
+        15 => day15(rng, size),
+        16 => day16(rng, size),
+        _ => bail!("No generator for day {}", day),
+    };
+
+    Ok(input)
+}
+
+fn lines<T: std::fmt::Display>(items: impl IntoIterator<Item = T>) -> String {
+    let mut s = String::new();
+
+    for item in items {
+        writeln!(s, "{}", item).unwrap();
+    }
+
+    s
+}
+
+/// Expenses with exactly one pair, and one triple, summing to 2020.
+fn day01(rng: &mut Rng, size: usize) -> String {
+    let a = rng.range(1, 2019);
+    let x = rng.range(1, 2018);
+    let y = rng.range(1, 2019 - x);
+    let mut nums = vec![a, 2020 - a, x, y, 2020 - x - y];
+    let mut set: HashSet<u64> = nums.iter().copied().collect();
+
+    if set.len() != nums.len() {
+        return day01(rng, size);
+    }
+
+    // Other numbers are over 1010, so that they can not make up a sum with
+    // each other, and must not make up one with the planted numbers either.
+    while nums.len() < size.clamp(5, 500) {
+        let n = rng.range(1011, 2019);
+
+        let makes_sum = set.contains(&n)
+            || set.contains(&(2020 - n))
+            || set.iter().chain(&[n]).any(|&m| {
+                m + n < 2020 && {
+                    let rest = 2020 - n - m;
+                    rest == n || set.contains(&rest)
+                }
+            });
+
+        if !makes_sum {
+            nums.push(n);
+            set.insert(n);
+        }
+    }
+
+    rng.shuffle(&mut nums);
+    lines(nums)
+}
+
+/// Password policies of the form `N-M L: password`.
+fn day02(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let n = rng.range(1, 8);
+        let m = rng.range(n + 1, n + 8);
+        let letter = *rng.pick(b"abcde") as char;
+        let len = rng.range(m, m + 6);
+        let password: String =
+            (0..len).map(|_| *rng.pick(b"abcdefgh") as char).collect();
+
+        format!("{}-{} {}: {}", n, m, letter, password)
+    }))
+}
+
+/// Map of trees, 31 squares wide.
+fn day03(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        (0..31)
+            .map(|_| {
+                if rng.ratio(1, 4) {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>()
+    }))
+}
+
+/// Passports with missing fields and both valid and invalid values.
+fn day04(rng: &mut Rng, size: usize) -> String {
+    let mut passports = Vec::new();
+
+    for _ in 0..size {
+        let valid = rng.ratio(1, 2);
+        let mut fields = vec![
+            format!("byr:{}", year(rng, 1920, 2002, valid)),
+            format!("iyr:{}", year(rng, 2010, 2020, valid)),
+            format!("eyr:{}", year(rng, 2020, 2030, valid)),
+            format!("hgt:{}", height(rng, valid)),
+            format!("hcl:{}", hair_color(rng, valid)),
+            format!("ecl:{}", eye_color(rng, valid)),
+            format!("pid:{}", passport_id(rng, valid)),
+        ];
+
+        if rng.ratio(1, 2) {
+            fields.push(format!("cid:{}", rng.range(1, 999)));
+        }
+
+        if rng.ratio(1, 4) {
+            fields.remove(rng.index(fields.len()));
+        }
+
+        rng.shuffle(&mut fields);
+
+        let mut passport = String::new();
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                passport.push(if rng.ratio(1, 3) {
+                    '\n'
+                } else {
+                    ' '
+                });
+            }
+            passport.push_str(field);
+        }
+
+        passports.push(passport);
+    }
+
+    passports.join("\n\n") + "\n"
+}
+
+fn year(rng: &mut Rng, min: u64, max: u64, valid: bool) -> u64 {
+    if valid || rng.ratio(1, 3) {
+        rng.range(min, max)
+    } else {
+        rng.range(max + 1, max + 20)
+    }
+}
+
+fn height(rng: &mut Rng, valid: bool) -> String {
+    if valid || rng.ratio(1, 3) {
+        if rng.ratio(1, 2) {
+            format!("{}cm", rng.range(150, 193))
+        } else {
+            format!("{}in", rng.range(59, 76))
+        }
+    } else {
+        match rng.range(0, 2) {
+            0 => format!("{}cm", rng.range(194, 250)),
+            1 => format!("{}in", rng.range(20, 58)),
+            _ => format!("{}", rng.range(59, 193)),
+        }
+    }
+}
+
+fn hair_color(rng: &mut Rng, valid: bool) -> String {
+    let hex: String =
+        (0..6).map(|_| *rng.pick(b"0123456789abcdef") as char).collect();
+
+    if valid || rng.ratio(1, 3) {
+        format!("#{}", hex)
+    } else {
+        hex
+    }
+}
+
+fn eye_color(rng: &mut Rng, valid: bool) -> &'static str {
+    const VALID: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+    const INVALID: [&str; 4] = ["zzz", "red", "xry", "blk"];
+
+    if valid || rng.ratio(1, 3) {
+        VALID[rng.index(VALID.len())]
+    } else {
+        INVALID[rng.index(INVALID.len())]
+    }
+}
+
+fn passport_id(rng: &mut Rng, valid: bool) -> String {
+    let len = if valid || rng.ratio(1, 3) {
+        9
+    } else {
+        *rng.pick(&[8, 10])
+    };
+
+    (0..len).map(|_| *rng.pick(b"0123456789") as char).collect()
+}
+
+/// Boarding passes of consecutive seats, except for exactly one.
+fn day05(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(2, 1000) as u64;
+    let first = rng.range(8, 1016 - size);
+    let missing = rng.range(first + 1, first + size - 1);
+    let mut ids: Vec<_> =
+        (first..=first + size).filter(|&id| id != missing).collect();
+
+    rng.shuffle(&mut ids);
+
+    lines(ids.into_iter().map(|id| {
+        let row = id / 8;
+        let col = id % 8;
+        let mut pass = String::new();
+
+        for bit in (0..7).rev() {
+            pass.push(if row & (1 << bit) != 0 {
+                'B'
+            } else {
+                'F'
+            });
+        }
+        for bit in (0..3).rev() {
+            pass.push(if col & (1 << bit) != 0 {
+                'R'
+            } else {
+                'L'
+            });
+        }
+
+        pass
+    }))
+}
+
+/// Groups of people answering questions a to z.
+fn day06(rng: &mut Rng, size: usize) -> String {
+    let groups: Vec<String> = (0..size)
+        .map(|_| {
+            let people = rng.range(1, 5);
+
+            lines((0..people).map(|_| {
+                let mut answers: Vec<char> = ('a'..='z').collect();
+                rng.shuffle(&mut answers);
+                answers.truncate(rng.range(1, 26) as usize);
+                answers.into_iter().collect::<String>()
+            }))
+        })
+        .collect();
+
+    groups.join("\n")
+}
+
+const ADJECTIVES: [&str; 12] = [
+    "light", "dark", "bright", "muted", "shiny", "faded", "dotted", "vibrant",
+    "dim", "pale", "drab", "wavy",
+];
+
+const COLORS: [&str; 12] = [
+    "red", "orange", "white", "yellow", "gold", "olive", "plum", "blue",
+    "black", "green", "teal", "tan",
+];
+
+/// Rules of which bags contain which, without cycles, and with shiny gold
+/// bags in there.
+fn day07(rng: &mut Rng, size: usize) -> String {
+    // Keeps part 2 from overflowing.
+    const MAX_INNER: u64 = 1_000_000;
+
+    let mut names: Vec<String> = ADJECTIVES
+        .iter()
+        .flat_map(|adj| COLORS.iter().map(move |c| format!("{} {}", adj, c)))
+        .filter(|name| name != "shiny gold")
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(2, names.len() + 1) - 1);
+    names.insert(rng.index(names.len() + 1), "shiny gold".to_owned());
+
+    // Bags only contain bags later in `names`, so rules are built backwards
+    // along with the number of bags each one holds in the end.
+    let mut inner = vec![0; names.len()];
+    let mut rules = vec![String::new(); names.len()];
+
+    for i in (0..names.len()).rev() {
+        let mut contents = Vec::new();
+        let mut total = 0;
+
+        if i + 1 < names.len() {
+            for _ in 0..rng.range(0, 4) {
+                let j = rng.range(i as u64 + 1, names.len() as u64 - 1);
+                let j = j as usize;
+                let n = rng.range(1, 5);
+                let count = total + n * (1 + inner[j]);
+
+                if count <= MAX_INNER && !contents.iter().any(|&(k, _)| k == j)
+                {
+                    contents.push((j, n));
+                    total = count;
+                }
+            }
+        }
+
+        inner[i] = total;
+        rules[i] = if contents.is_empty() {
+            format!("{} bags contain no other bags.", names[i])
+        } else {
+            let contents: Vec<_> = contents
+                .iter()
+                .map(|&(j, n)| {
+                    let s = if n == 1 {
+                        ""
+                    } else {
+                        "s"
+                    };
+                    format!("{} {} bag{}", n, names[j], s)
+                })
+                .collect();
+
+            format!("{} bags contain {}.", names[i], contents.join(", "))
+        };
+    }
+
+    rng.shuffle(&mut rules);
+    lines(rules)
+}
+
+/// Programs that loop forever, with exactly one `jmp` or `nop` that makes
+/// them terminate once swapped.
+///
+/// The terminating program executes forward only. Every instruction it
+/// skips, and every `nop` that it executes, points back to an instruction
+/// executed before the swapped one, so that no other swap terminates.
+fn day08(rng: &mut Rng, size: usize) -> String {
+    let len = size.max(4);
+    let mut executed = Vec::new();
+    let mut jumps = vec![None; len];
+    let mut pc = 0;
+
+    while pc < len {
+        executed.push(pc);
+
+        let step = rng.range(2, 4) as usize;
+        if rng.ratio(1, 3) && pc + step <= len {
+            jumps[pc] = Some(step);
+            pc += step;
+        } else {
+            pc += 1;
+        }
+    }
+
+    let steps: Vec<_> =
+        executed.iter().copied().filter(|&pc| jumps[pc].is_none()).collect();
+    if steps.is_empty() {
+        return day08(rng, size);
+    }
+    let swapped = *rng.pick(&steps);
+    let targets: Vec<_> =
+        executed.iter().copied().filter(|&pc| pc <= swapped).collect();
+    let offset = |rng: &mut Rng, from: usize| {
+        let to = *rng.pick(&targets);
+        to as i64 - from as i64
+    };
+
+    lines((0..len).map(|pc| {
+        if pc == swapped {
+            format!("jmp {:+}", offset(rng, pc))
+        } else if let Some(step) = jumps[pc] {
+            format!("jmp {:+}", step)
+        } else if !executed.contains(&pc) {
+            format!("jmp {:+}", offset(rng, pc))
+        } else if rng.ratio(1, 3) {
+            format!("nop {:+}", offset(rng, pc))
+        } else {
+            format!("acc {:+}", rng.range(0, 100) as i64 - 50)
+        }
+    }))
+}
+
+/// XMAS data with a preamble of 25, where exactly one number is not the
+/// sum of two of the 25 before it, but is the sum of a contiguous run.
+fn day09(rng: &mut Rng, size: usize) -> String {
+    const PREAMBLE: usize = 25;
+
+    let len = size.max(PREAMBLE + 3);
+    let invalid_at = rng.range(PREAMBLE as u64 + 2, len as u64 - 1) as usize;
+    let mut nums: Vec<u64> = Vec::with_capacity(len);
+
+    let mut preamble: Vec<u64> = (1..=PREAMBLE as u64 * 2).collect();
+    rng.shuffle(&mut preamble);
+    nums.extend(&preamble[..PREAMBLE]);
+
+    let is_sum = |window: &[u64], n: u64| {
+        window.iter().any(|&x| window.iter().any(|&y| x != y && x + y == n))
+    };
+
+    while nums.len() < len {
+        let window = &nums[nums.len() - PREAMBLE..];
+
+        if nums.len() == invalid_at {
+            // A run of at least two numbers, not necessarily in the window.
+            let end = rng.range(2, nums.len() as u64) as usize;
+            let start = rng.range(0, end as u64 - 2) as usize;
+            let n: u64 = nums[start..end].iter().sum();
+
+            // A number equal to `n` would be a run of a single number.
+            if is_sum(window, n) || nums.contains(&n) {
+                return day09(rng, size);
+            }
+
+            nums.push(n);
+            continue;
+        }
+
+        // Prefer the smallest numbers so that they do not grow too fast.
+        let mut sorted = window.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        sorted.truncate(5);
+
+        if sorted.len() < 2 {
+            return day09(rng, size);
+        }
+
+        let i = rng.index(sorted.len() - 1);
+        let j = rng.range(i as u64 + 1, sorted.len() as u64 - 1) as usize;
+        nums.push(sorted[i] + sorted[j]);
+    }
+
+    lines(nums)
+}
+
+/// Adapters with joltages 1 to 3 apart.
+fn day10(rng: &mut Rng, size: usize) -> String {
+    let mut joltage = 0;
+    let mut joltages: Vec<u64> = (0..size)
+        .map(|_| {
+            joltage += *rng.pick(&[1, 1, 1, 2, 3, 3]);
+            joltage
+        })
+        .collect();
+
+    rng.shuffle(&mut joltages);
+    lines(joltages)
+}
+
+/// Square seat layout, regenerated until the seats stabilize under both
+/// sets of rules, as random layouts may cycle forever instead.
+fn day11(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, 60);
+
+    loop {
+        let input = lines((0..size).map(|_| {
+            (0..size)
+                .map(|_| {
+                    if rng.ratio(4, 5) {
+                        'L'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        }));
+
+        let layout = Day11::parse(&input).unwrap();
+        if Day11::part1(&layout).is_ok() && Day11::part2(&layout).is_ok() {
+            return input;
+        }
+    }
+}
+
+/// Navigation instructions, turning by multiples of 90 degrees.
+fn day12(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| match rng.range(0, 6) {
+        0..=4 => format!(
+            "{}{}",
+            rng.pick(&['N', 'S', 'E', 'W', 'F']),
+            rng.range(1, 100)
+        ),
+        _ => format!("{}{}", rng.pick(&['L', 'R']), rng.pick(&[90, 180, 270])),
+    }))
+}
+
+const PRIMES: [u64; 20] = [
+    7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79,
+    83,
+];
+
+/// Earliest timestamp and bus IDs, most of them out of service.
+fn day13(rng: &mut Rng, size: usize) -> String {
+    let mut buses = PRIMES.to_vec();
+    rng.shuffle(&mut buses);
+    buses.truncate(size.clamp(1, PRIMES.len()));
+
+    let mut schedule = Vec::new();
+    for bus in buses {
+        for _ in 0..rng.range(0, 3) {
+            schedule.push("x".to_owned());
+        }
+        schedule.push(bus.to_string());
+    }
+
+    format!("{}\n{}\n", rng.range(1_000, 1_000_000), schedule.join(","))
+}
+
+/// Initialization program with masks of at most 9 floating bits.
+fn day14(rng: &mut Rng, size: usize) -> String {
+    let mut lines = Vec::new();
+
+    for i in 0..size {
+        if i == 0 || rng.ratio(1, 4) {
+            let mut mask: Vec<char> = (0..36)
+                .map(|_| {
+                    if rng.ratio(1, 2) {
+                        '1'
+                    } else {
+                        '0'
+                    }
+                })
+                .collect();
+
+            for _ in 0..rng.range(0, 9) {
+                mask[rng.index(36)] = 'X';
+            }
+
+            lines.push(format!("mask = {}", mask.iter().collect::<String>()));
+        } else {
+            lines.push(format!(
+                "mem[{}] = {}",
+                rng.range(0, 65535),
+                rng.range(0, (1 << 36) - 1)
+            ));
+        }
+    }
+
+    self::lines(lines)
+}
+
+/// Distinct starting numbers.
+fn day15(rng: &mut Rng, size: usize) -> String {
+    let mut nums: Vec<_> = (0..20).collect();
+    rng.shuffle(&mut nums);
+    nums.truncate(size.clamp(1, 20));
+
+    let nums: Vec<_> = nums.iter().map(u64::to_string).collect();
+    format!("{}\n", nums.join(","))
+}
+
+/// Ticket rules, my ticket and `size` nearby tickets, some of them invalid.
+///
+/// The values of each ticket position fall in a band of their own, and the
+/// rules cover nested sets of bands, so that exactly one rule fits only one
+/// position, another only two, and so on.
+fn day16(rng: &mut Rng, size: usize) -> String {
+    const FIELDS: u64 = 20;
+    const BAND: u64 = 50;
+
+    let names = [
+        "departure location",
+        "departure station",
+        "departure platform",
+        "departure track",
+        "departure date",
+        "departure time",
+        "arrival location",
+        "arrival station",
+        "arrival platform",
+        "arrival track",
+        "class",
+        "duration",
+        "price",
+        "route",
+        "row",
+        "seat",
+        "train",
+        "type",
+        "wagon",
+        "zone",
+    ];
+
+    // Position of the band `b`, 1 based.
+    let mut positions: Vec<u64> = (0..FIELDS).collect();
+    rng.shuffle(&mut positions);
+
+    let mut rules: Vec<String> = (1..=FIELDS)
+        .map(|bands| {
+            // `range1` covers the first `split` bands, `range2` the rest.
+            let split = rng.range(0, bands);
+            let (lo1, hi1) = if split == 0 {
+                let hi1 = rng.range(1, BAND);
+                (rng.range(1, hi1), hi1)
+            } else {
+                let hi1 = if split == bands {
+                    rng.range(BAND * split + 45, BAND * split + 49)
+                } else {
+                    rng.range(BAND * split + 45, BAND * split + 52)
+                };
+                (rng.range(1, BAND + 5), hi1)
+            };
+            let (lo2, hi2) = if split == bands {
+                let lo2 = rng.range(hi1 + 1, BAND * bands + 51);
+                (lo2, rng.range(lo2, BAND * bands + 54))
+            } else {
+                let lo2 = rng.range(hi1 + 1, BAND * (split + 1) + 5);
+                (lo2, rng.range(BAND * bands + 44, BAND * bands + 54))
+            };
+
+            format!(
+                "{}: {}-{} or {}-{}",
+                names[bands as usize - 1],
+                lo1,
+                hi1,
+                lo2,
+                hi2
+            )
+        })
+        .collect();
+    rng.shuffle(&mut rules);
+
+    let ticket = |rng: &mut Rng, invalid: bool| {
+        let mut values = vec![0; FIELDS as usize];
+
+        for (band, &pos) in (1..=FIELDS).zip(&positions) {
+            values[pos as usize] = rng.range(BAND * band + 5, BAND * band + 44);
+        }
+
+        if invalid {
+            let band = FIELDS + 1;
+            let i = rng.index(values.len());
+            values[i] = rng.range(BAND * band + 5, BAND * band + 44);
+        }
+
+        let values: Vec<_> = values.iter().map(u64::to_string).collect();
+        values.join(",")
+    };
+
+    let mine = ticket(rng, false);
+    let nearby: Vec<_> = (0..size)
+        .map(|_| {
+            let invalid = rng.ratio(1, 4);
+            ticket(rng, invalid)
+        })
+        .collect();
+
+    format!(
+        "{}\nyour ticket:\n{}\n\nnearby tickets:\n{}",
+        lines(rules),
+        mine,
+        lines(nearby)
+    )
+}
+
+#[test]
+fn test_rng() {
+    let mut a = Rng::new(7);
+    let mut b = Rng::new(7);
+
+    for _ in 0..1000 {
+        let x = a.range(3, 9);
+        assert!((3..=9).contains(&x));
+        assert_eq!(x, b.range(3, 9));
+    }
+
+    assert_eq!(generate(16, &mut Rng::new(1), 10).unwrap(), {
+        generate(16, &mut Rng::new(1), 10).unwrap()
+    });
+    assert!(generate(26, &mut Rng::new(1), 10).is_err());
+}
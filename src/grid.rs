@@ -0,0 +1,233 @@
+use anyhow::{bail, Result};
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// Up, right, down and left.
+pub const DIRECTIONS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// `DIRECTIONS4` and the diagonals, clockwise from up-left.
+pub const DIRECTIONS8: [(isize, isize); 8] =
+    [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+
+/// Two dimensional grid stored row by row. Positions are `(x, y)`, with `x`
+/// the column and `y` the row, starting from the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from `cells`, given row by row.
+    pub fn new(width: usize, cells: Vec<T>) -> Result<Grid<T>> {
+        if width == 0 || cells.is_empty() {
+            bail!("Empty grid");
+        }
+
+        if !cells.len().is_multiple_of(width) {
+            bail!("{} cells do not fit in rows of {}", cells.len(), width);
+        }
+
+        Ok(Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses a grid with a cell per character, mapped by `f`. All lines
+    /// must have the same length.
+    pub fn parse<F>(input: &str, mut f: F) -> Result<Grid<T>>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for ln in input.lines() {
+            let len_before = cells.len();
+
+            for (i, c) in ln.char_indices() {
+                match f(c) {
+                    Some(cell) => cells.push(cell),
+                    None => {
+                        let span = &ln[i..i + c.len_utf8()];
+                        let msg = format!("Invalid character '{}'", c);
+                        return Err(ParseError::new(input, span, msg).into());
+                    }
+                }
+            }
+
+            let len = cells.len() - len_before;
+
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    let msg =
+                        format!("Expected {} cells, found {}", width, len);
+                    return Err(ParseError::new(input, ln, msg).into());
+                }
+                Some(_) => {}
+            }
+        }
+
+        Grid::new(width.unwrap_or(0), cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Like `get`, but as if the grid repeated forever in all directions.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+
+        &self[(x, y)]
+    }
+
+    /// Moves `(x, y)` by `(dx, dy)`, unless that leaves the grid.
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// All cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The up to 4 positions next to `pos`, horizontally or vertically.
+    pub fn neighbours4(
+        &self,
+        pos: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DIRECTIONS4.iter().filter_map(move |&dir| self.offset(pos, dir))
+    }
+
+    /// The up to 8 positions next to `pos`, diagonals included.
+    pub fn neighbours8(
+        &self,
+        pos: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        DIRECTIONS8.iter().filter_map(move |&dir| self.offset(pos, dir))
+    }
+
+    /// The positions seen from `pos` looking towards `dir`, up to the edge
+    /// of the grid. `pos` itself is not included.
+    pub fn ray(
+        &self,
+        pos: (usize, usize),
+        dir: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut cur = Some(pos);
+
+        std::iter::from_fn(move || {
+            cur = self.offset(cur?, dir);
+            cur
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!("({}, {}) out of {}x{} grid", x, y, self.width, self.height)
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(x, y).unwrap_or_else(|| {
+            panic!("({}, {}) out of {}x{} grid", x, y, width, height)
+        })
+    }
+}
+
+/// Renders a line per row, with each cell displayed in turn.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_grid() {
+    let mut grid = Grid::parse("123\n456\n", |c| c.to_digit(10)).unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 0)], 3);
+    assert_eq!(grid.get(0, 1), Some(&4));
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(*grid.get_wrapping(-1, 3), 6);
+    assert_eq!(*grid.get_wrapping(4, -2), 2);
+
+    assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    assert_eq!(grid.ray((0, 0), (1, 0)).collect::<Vec<_>>(), [(1, 0), (2, 0)]);
+    assert_eq!(grid.ray((0, 1), (1, -1)).collect::<Vec<_>>(), [(1, 0)]);
+    assert_eq!(grid.ray((2, 1), (1, 1)).count(), 0);
+
+    grid[(1, 1)] = 0;
+    assert_eq!(grid.to_string(), "123\n406\n");
+
+    assert!(Grid::parse("12\n3\n", |c| c.to_digit(10)).is_err());
+    assert!(Grid::parse("1x\n", |c| c.to_digit(10)).is_err());
+    assert!(Grid::parse("", |c| c.to_digit(10)).is_err());
+}
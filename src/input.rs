@@ -0,0 +1,109 @@
+use anyhow::{Context, Result};
+use std::env;
+use std::ffi::OsString;
+use std::fmt::{self, Display};
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+
+use crate::file_lines;
+
+/// Environment variable pointing to a directory with `inputNN.txt` files.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Where the input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Resolves the input of `day`.
+    ///
+    /// An explicit `arg` always wins, with `-` meaning stdin. Otherwise the
+    /// input is looked up in `$AOC_INPUT_DIR`, falling back to `./data`.
+    pub fn resolve(day: u32, arg: Option<&str>) -> InputSource {
+        resolve_with(day, arg, env::var_os(INPUT_DIR_ENV))
+    }
+
+    pub fn read_lines(&self) -> Result<Vec<String>> {
+        match self {
+            InputSource::Path(path) => file_lines(path)?.collect(),
+            InputSource::Stdin => io::stdin()
+                .lock()
+                .lines()
+                .enumerate()
+                .map(|(i, res)| {
+                    res.with_context(|| {
+                        format!("Failed to read {} at line {}", self, i + 1)
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Reads the whole input, with each line terminated by `\n`.
+    pub fn read(&self) -> Result<String> {
+        let mut s = String::new();
+
+        for line in self.read_lines()? {
+            s.push_str(&line);
+            s.push('\n');
+        }
+
+        Ok(s)
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "'{}'", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// Directory holding the inputs: `$AOC_INPUT_DIR`, or `./data` if unset.
+pub fn input_dir() -> PathBuf {
+    input_dir_with(env::var_os(INPUT_DIR_ENV))
+}
+
+fn input_dir_with(input_dir: Option<OsString>) -> PathBuf {
+    input_dir
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("./data"))
+}
+
+fn resolve_with(
+    day: u32,
+    arg: Option<&str>,
+    input_dir: Option<OsString>,
+) -> InputSource {
+    match arg {
+        Some("-") => InputSource::Stdin,
+        Some(path) => InputSource::Path(PathBuf::from(path)),
+        None => {
+            let dir = input_dir_with(input_dir);
+            InputSource::Path(dir.join(format!("input{:02}.txt", day)))
+        }
+    }
+}
+
+#[test]
+fn test_resolve() {
+    assert_eq!(resolve_with(7, Some("-"), None), InputSource::Stdin);
+    assert_eq!(
+        resolve_with(7, Some("my.txt"), Some("/tmp".into())),
+        InputSource::Path("my.txt".into())
+    );
+    assert_eq!(
+        resolve_with(7, None, Some("/tmp/aoc".into())),
+        InputSource::Path("/tmp/aoc/input07.txt".into())
+    );
+    assert_eq!(
+        resolve_with(15, None, None),
+        InputSource::Path("./data/input15.txt".into())
+    );
+}
@@ -3,16 +3,110 @@ use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
-pub fn file_lines<P>(path: P) -> Result<impl Iterator<Item = String>>
+mod answers;
+pub mod bench;
+pub mod client;
+pub mod gen;
+pub mod grid;
+mod input;
+pub mod memory;
+pub mod output;
+pub mod parallel;
+pub mod parse;
+pub mod reference;
+mod solution;
+pub mod submit;
+pub mod visualize;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+
+pub use answers::Answers;
+pub use grid::Grid;
+pub use input::{input_dir, InputSource, INPUT_DIR_ENV};
+pub use parse::ParseError;
+pub use solution::{
+    find_day, run, run_args, run_parts, Args, Day, Part, Puzzle, Solution, DAYS,
+};
+
+/// Iterates over the lines of `path`, failing on the first line that can
+/// not be read or is not valid UTF-8.
+pub fn file_lines<P>(path: P) -> Result<impl Iterator<Item = Result<String>>>
 where
     P: AsRef<Path>,
 {
-    let path = path.as_ref();
-    let f = File::open(path)
+    let path = path.as_ref().to_owned();
+    let f = File::open(&path)
         .with_context(|| format!("Failed to open '{}'", path.display()))?;
 
-    Ok(BufReader::new(f)
-        .lines()
-        .take_while(|res| res.is_ok())
-        .map(|res| res.unwrap()))
+    Ok(BufReader::new(f).lines().enumerate().map(move |(i, res)| {
+        res.with_context(|| {
+            format!("Failed to read '{}' at line {}", path.display(), i + 1)
+        })
+    }))
+}
+
+/// Reads all the lines of `path`.
+pub fn read_lines<P>(path: P) -> Result<Vec<String>>
+where
+    P: AsRef<Path>,
+{
+    file_lines(path)?.collect()
+}
+
+/// Splits `input` into groups of consecutive non-blank lines, with trailing
+/// whitespace removed. Any number of blank lines, including lines with only
+/// whitespace, separates groups.
+pub fn groups(input: &str) -> impl Iterator<Item = Vec<&str>> {
+    let mut lines = input.lines().map(str::trim_end).peekable();
+
+    std::iter::from_fn(move || {
+        while lines.next_if(|ln| ln.is_empty()).is_some() {}
+        lines.peek()?;
+
+        let mut group = Vec::new();
+
+        while let Some(ln) = lines.next_if(|ln| !ln.is_empty()) {
+            group.push(ln);
+        }
+
+        Some(group)
+    })
+}
+
+#[test]
+fn test_groups() {
+    let input = "\n a\r\nb  \r\n\r\n \t\n\nc\n\n";
+    assert_eq!(groups(input).collect::<Vec<_>>(), [vec![" a", "b"], vec!["c"]]);
+    assert_eq!(groups("").count(), 0);
+    assert_eq!(groups("\n\n").count(), 0);
+}
+
+#[test]
+fn test_read_lines_invalid_utf8() {
+    let path = std::env::temp_dir().join("aoc-2020-invalid-utf8.txt");
+    std::fs::write(&path, b"1\n2\n\xff\n4\n").unwrap();
+
+    let err = read_lines(&path).unwrap_err();
+    assert!(format!("{:#}", err).contains("at line 3"));
+
+    let lines: Vec<_> = file_lines(&path).unwrap().take(2).collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines.iter().all(|ln| ln.is_ok()));
+
+    std::fs::remove_file(&path).unwrap();
 }
@@ -0,0 +1,174 @@
+//! Heap usage of solutions, measured by a counting global allocator.
+//!
+//! Binaries opt in by installing `CountingAlloc` as their global allocator
+//! and calling `enable`. Counters are per thread, so that parts solved
+//! concurrently are measured apart.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters {
+            current: Cell::new(0),
+            peak: Cell::new(0),
+            allocations: Cell::new(0),
+            allocated: Cell::new(0),
+        }
+    };
+}
+
+struct Counters {
+    /// Bytes allocated minus bytes freed by this thread. Negative when the
+    /// thread frees memory allocated by another.
+    current: Cell<isize>,
+    peak: Cell<isize>,
+    allocations: Cell<usize>,
+    allocated: Cell<usize>,
+}
+
+impl Counters {
+    fn add(&self, size: usize) {
+        let current = self.current.get() + size as isize;
+
+        self.current.set(current);
+        self.peak.set(self.peak.get().max(current));
+        self.allocations.set(self.allocations.get() + 1);
+        self.allocated.set(self.allocated.get() + size);
+    }
+
+    fn sub(&self, size: usize) {
+        self.current.set(self.current.get() - size as isize);
+    }
+}
+
+/// Global allocator that counts the allocations of each thread, once
+/// counting is enabled.
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() && is_enabled() {
+            let _ = COUNTERS.try_with(|c| c.add(layout.size()));
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() && is_enabled() {
+            let _ = COUNTERS.try_with(|c| c.add(layout.size()));
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+
+        if is_enabled() {
+            let _ = COUNTERS.try_with(|c| c.sub(layout.size()));
+        }
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+
+        if !new_ptr.is_null() && is_enabled() {
+            let _ = COUNTERS.try_with(|c| {
+                c.sub(layout.size());
+                c.add(new_size);
+            });
+        }
+
+        new_ptr
+    }
+}
+
+/// Starts counting allocations. Requires `CountingAlloc` to be the global
+/// allocator.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Heap usage of a measured function.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Memory {
+    /// Most bytes in use at once, on top of those in use before.
+    pub peak: usize,
+    pub allocations: usize,
+    /// Total bytes allocated, including reallocations.
+    pub allocated: usize,
+}
+
+/// Runs `f` and measures the heap usage of the current thread meanwhile,
+/// if counting is enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Memory>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+
+    let start = COUNTERS.with(|c| {
+        c.peak.set(c.current.get());
+        (c.current.get(), c.allocations.get(), c.allocated.get())
+    });
+
+    let result = f();
+
+    let memory = COUNTERS.with(|c| Memory {
+        peak: (c.peak.get() - start.0).max(0) as usize,
+        allocations: c.allocations.get() - start.1,
+        allocated: c.allocated.get() - start.2,
+    });
+
+    (result, Some(memory))
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 MiB`.
+pub struct Bytes(pub usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+        let mut size = self.0 as f64;
+        let mut unit = 0;
+
+        while size >= 1024.0 && unit + 1 < UNITS.len() {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        let s = if unit == 0 {
+            format!("{} B", self.0)
+        } else {
+            format!("{:.1} {}", size, UNITS[unit])
+        };
+
+        f.pad(&s)
+    }
+}
+
+#[test]
+fn test_bytes() {
+    assert_eq!(Bytes(512).to_string(), "512 B");
+    assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+    assert_eq!(format!("{:>9}", Bytes(3 << 20)), "  3.0 MiB");
+}
@@ -0,0 +1,278 @@
+use anyhow::{bail, Result};
+use std::fmt::Write as _;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::memory::{Bytes, Memory};
+use crate::{ParseError, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => bail!("Invalid format '{}', expected text, json or csv", s),
+        }
+    }
+}
+
+/// Outcome of solving a part, with either an answer or an error.
+#[derive(Debug)]
+pub struct Record {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Result<String>,
+    pub duration: Duration,
+    /// Heap usage while solving, if measured.
+    pub memory: Option<Memory>,
+}
+
+/// Renders `records` in `format`.
+///
+/// JSON and CSV share the same fields: `day`, `part`, `answer`,
+/// `duration_ns` and `error`, where exactly one of `answer` and `error` is
+/// set. If the heap usage was measured, `peak_bytes`, `allocations` and
+/// `allocated_bytes` follow `duration_ns`.
+pub fn render(records: &[Record], format: Format) -> String {
+    match format {
+        Format::Text => render_text(records),
+        Format::Json => render_json(records),
+        Format::Csv => render_csv(records),
+    }
+}
+
+fn render_text(records: &[Record]) -> String {
+    let with_memory = has_memory(records);
+    let mut s = String::from("Day  Part        Time  ");
+
+    if with_memory {
+        s.push_str("      Peak   Allocs   Allocated  ");
+    }
+    s.push_str("Answer\n");
+
+    for rec in records {
+        let time = format!("{:.1?}", rec.duration);
+        write!(s, "{:>3}  {:>4}  {:>10}  ", rec.day, rec.part, time).unwrap();
+
+        if with_memory {
+            let mem = rec.memory.unwrap_or_default();
+            write!(
+                s,
+                "{:>10}  {:>7}  {:>10}  ",
+                Bytes(mem.peak),
+                mem.allocations,
+                Bytes(mem.allocated)
+            )
+            .unwrap();
+        }
+
+        match &rec.answer {
+            Ok(answer) => writeln!(s, "{}", answer),
+            Err(e) => writeln!(s, "error: {:#}", e),
+        }
+        .unwrap();
+    }
+
+    s
+}
+
+fn has_memory(records: &[Record]) -> bool {
+    records.iter().any(|rec| rec.memory.is_some())
+}
+
+/// Renders the parse errors of `records` with their offending lines, once
+/// per day.
+pub fn diagnostics(records: &[Record]) -> String {
+    let mut s = String::new();
+    let mut prev_day = None;
+
+    for rec in records {
+        let parse_err = match &rec.answer {
+            Err(e) => e.downcast_ref::<ParseError>(),
+            Ok(_) => None,
+        };
+
+        if let Some(pe) = parse_err {
+            if prev_day != Some(rec.day) {
+                write!(s, "Day {}: {}", rec.day, pe.render()).unwrap();
+                prev_day = Some(rec.day);
+            }
+        }
+    }
+
+    s
+}
+
+fn render_json(records: &[Record]) -> String {
+    let with_memory = has_memory(records);
+    let mut s = String::from("[\n");
+
+    for (i, rec) in records.iter().enumerate() {
+        let (answer, error) = match &rec.answer {
+            Ok(answer) => (json_string(answer), "null".to_owned()),
+            Err(e) => ("null".to_owned(), json_string(&format!("{:#}", e))),
+        };
+
+        let memory = match (with_memory, rec.memory) {
+            (false, _) => String::new(),
+            (true, Some(mem)) => format!(
+                ", \"peak_bytes\": {}, \"allocations\": {}, \
+                 \"allocated_bytes\": {}",
+                mem.peak, mem.allocations, mem.allocated
+            ),
+            (true, None) => ", \"peak_bytes\": null, \"allocations\": null, \
+                             \"allocated_bytes\": null"
+                .to_owned(),
+        };
+
+        writeln!(
+            s,
+            "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \
+             \"duration_ns\": {}{}, \"error\": {}}}{}",
+            rec.day,
+            rec.part,
+            answer,
+            rec.duration.as_nanos(),
+            memory,
+            error,
+            if i + 1 < records.len() {
+                ","
+            } else {
+                ""
+            }
+        )
+        .unwrap();
+    }
+
+    s.push_str("]\n");
+    s
+}
+
+fn render_csv(records: &[Record]) -> String {
+    let with_memory = has_memory(records);
+    let mut s = String::from(if with_memory {
+        "day,part,answer,duration_ns,peak_bytes,allocations,allocated_bytes,\
+         error\n"
+    } else {
+        "day,part,answer,duration_ns,error\n"
+    });
+
+    for rec in records {
+        let (answer, error) = match &rec.answer {
+            Ok(answer) => (csv_field(answer), String::new()),
+            Err(e) => (String::new(), csv_field(&format!("{:#}", e))),
+        };
+
+        let memory = match (with_memory, rec.memory) {
+            (false, _) => String::new(),
+            (true, Some(mem)) => {
+                format!("{},{},{},", mem.peak, mem.allocations, mem.allocated)
+            }
+            (true, None) => ",,,".to_owned(),
+        };
+
+        writeln!(
+            s,
+            "{},{},{},{},{}{}",
+            rec.day,
+            rec.part,
+            answer,
+            rec.duration.as_nanos(),
+            memory,
+            error
+        )
+        .unwrap();
+    }
+
+    s
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                write!(out, "\\u{:04x}", c as u32).unwrap();
+            }
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+#[test]
+fn test_render() {
+    let mut records = [
+        Record {
+            day: 7,
+            part: Part::One,
+            answer: Ok("337".to_owned()),
+            duration: Duration::from_nanos(1500),
+            memory: None,
+        },
+        Record {
+            day: 13,
+            part: Part::Two,
+            answer: Err(anyhow::anyhow!("not \"solved\", yet")),
+            duration: Duration::from_nanos(20),
+            memory: None,
+        },
+    ];
+
+    assert_eq!(
+        render(&records, Format::Json),
+        "[\n  {\"day\": 7, \"part\": 1, \"answer\": \"337\", \
+         \"duration_ns\": 1500, \"error\": null},\n  \
+         {\"day\": 13, \"part\": 2, \"answer\": null, \"duration_ns\": 20, \
+         \"error\": \"not \\\"solved\\\", yet\"}\n]\n"
+    );
+
+    assert_eq!(
+        render(&records, Format::Csv),
+        "day,part,answer,duration_ns,error\n\
+         7,1,337,1500,\n\
+         13,2,,20,\"not \"\"solved\"\", yet\"\n"
+    );
+
+    records[0].memory = Some(Memory {
+        peak: 2048,
+        allocations: 3,
+        allocated: 4096,
+    });
+
+    assert_eq!(
+        render(&records, Format::Csv),
+        "day,part,answer,duration_ns,peak_bytes,allocations,allocated_bytes,\
+         error\n\
+         7,1,337,1500,2048,3,4096,\n\
+         13,2,,20,,,,\"not \"\"solved\"\", yet\"\n"
+    );
+}
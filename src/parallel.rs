@@ -0,0 +1,53 @@
+//! Solving several days at once on a pool of worker threads.
+
+use anyhow::Result;
+use once_cell::sync::OnceCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::output::Record;
+use crate::{Day, InputSource, Part, Puzzle};
+
+/// Solves `parts` of every day, each with its input, on `jobs` threads.
+///
+/// Each day is parsed once, by the first worker that needs it, and its parts
+/// are then solved independently of each other. The records are in the same
+/// order as with `Day::run` on each day in turn, whatever the number of jobs.
+/// A part that panics fails with an error, like with `Day::run`, and leaves
+/// the other parts and days running.
+pub fn run_parallel(
+    days: &[(&Day, InputSource)],
+    parts: &[Part],
+    jobs: usize,
+    strict: bool,
+) -> Vec<Record> {
+    let puzzles: Vec<OnceCell<Result<Box<dyn Puzzle>>>> =
+        days.iter().map(|_| OnceCell::new()).collect();
+    let tasks: Vec<(usize, Part)> = (0..days.len())
+        .flat_map(|i| parts.iter().map(move |&part| (i, part)))
+        .collect();
+    let records: Vec<Mutex<Option<Record>>> =
+        tasks.iter().map(|_| Mutex::new(None)).collect();
+    let next = AtomicUsize::new(0);
+
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            s.spawn(|| loop {
+                let task = next.fetch_add(1, Ordering::Relaxed);
+                let (i, part) = match tasks.get(task) {
+                    Some(&task) => task,
+                    None => break,
+                };
+
+                let (day, input) = &days[i];
+                let puzzle = puzzles[i].get_or_init(|| day.load(input, strict));
+                let record = day.solve(puzzle, part);
+
+                *records[task].lock().unwrap() = Some(record);
+            });
+        }
+    });
+
+    records.into_iter().map(|rec| rec.into_inner().unwrap().unwrap()).collect()
+}
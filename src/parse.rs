@@ -0,0 +1,189 @@
+use anyhow::Result;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Error pointing at the offending text of an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    /// Where the error is, if its span was found in the input.
+    pub location: Option<Location>,
+    pub message: String,
+}
+
+/// Position of the offending text of an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// Line number, starting from 1.
+    pub line: usize,
+    /// Column in characters, starting from 1.
+    pub column: usize,
+    /// The whole offending line.
+    pub text: String,
+    /// Length in characters of the offending part of `text`.
+    pub len: usize,
+}
+
+impl ParseError {
+    /// Creates an error for `span`, a slice of `input`. A span that is not
+    /// a slice of `input`, such as a copy of it, has no location.
+    pub fn new(input: &str, span: &str, message: impl Display) -> ParseError {
+        ParseError {
+            file: None,
+            location: Location::find(input, span),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn with_file(mut self, path: impl AsRef<Path>) -> ParseError {
+        self.file = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// Renders the error followed by the offending line, with the offending
+    /// part pointed out by carets.
+    pub fn render(&self) -> String {
+        let loc = match &self.location {
+            Some(loc) => loc,
+            None => return format!("{}\n", self),
+        };
+        let line = loc.line.to_string();
+        let pad = " ".repeat(line.len());
+
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}{}\n",
+            self,
+            pad,
+            line,
+            loc.text,
+            pad,
+            " ".repeat(loc.column - 1),
+            "^".repeat(loc.len.max(1))
+        )
+    }
+}
+
+impl Location {
+    /// Locates `span` within `input` from their addresses.
+    fn find(input: &str, span: &str) -> Option<Location> {
+        let offset =
+            (span.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+
+        if offset + span.len() > input.len() {
+            return None;
+        }
+
+        let before = input.get(..offset)?;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end =
+            input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+        let text = input[line_start..line_end].trim_end_matches('\r');
+        let span = &span[..span.len().min(line_end - offset)];
+
+        Some(Location {
+            line: before.matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: text.to_owned(),
+            len: span.trim_end_matches('\r').chars().count(),
+        })
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        if let Some(loc) = &self.location {
+            write!(f, " at line {}, column {}", loc.line, loc.column)?;
+        }
+
+        if let Some(file) = &self.file {
+            write!(f, " of '{}'", file.display())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `span`, a slice of `input`, as a `T`.
+pub fn parse_span<T>(input: &str, span: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    span.parse().map_err(|e| {
+        ParseError::new(input, span, format!("Invalid '{}': {}", span, e))
+            .into()
+    })
+}
+
+/// Parses each line of `input` with `f`. Errors that do not already point
+/// at their offending text point at the whole line.
+pub fn parse_lines<'a, T, F>(input: &'a str, mut f: F) -> Result<Vec<T>>
+where
+    F: FnMut(&'a str) -> Result<T>,
+{
+    input.lines().map(|ln| f(ln).map_err(|e| at_line(input, ln, e))).collect()
+}
+
+/// Like `parse_lines`, but lines that fail to parse are skipped, unless
+/// `strict`.
+pub fn filter_lines<'a, T, F>(
+    input: &'a str,
+    strict: bool,
+    mut f: F,
+) -> Result<Vec<T>>
+where
+    F: FnMut(&'a str) -> Result<T>,
+{
+    if strict {
+        return parse_lines(input, f);
+    }
+
+    Ok(input.lines().filter_map(|ln| f(ln).ok()).collect())
+}
+
+fn at_line(input: &str, ln: &str, e: anyhow::Error) -> anyhow::Error {
+    if e.is::<ParseError>() {
+        e
+    } else {
+        ParseError::new(input, ln, format!("{:#}", e)).into()
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    let input = "12\r\n3x4\r\n";
+
+    let err = parse_lines(input, |ln| parse_span::<u32>(input, ln))
+        .unwrap_err()
+        .downcast::<ParseError>()
+        .unwrap();
+    let loc = err.location.unwrap();
+    assert_eq!((loc.line, loc.column, loc.len), (2, 1, 3));
+    assert_eq!(loc.text, "3x4");
+
+    let err = ParseError::new(input, &input[5..6], "Invalid digit")
+        .with_file("input.txt");
+    assert_eq!(
+        err.render(),
+        "Invalid digit at line 2, column 2 of 'input.txt'\n  \
+         |\n2 | 3x4\n  |  ^\n"
+    );
+
+    // A copy of the input can not be located in it.
+    let copy = input[5..6].to_owned();
+    let err = ParseError::new(input, &copy, "Invalid digit");
+    assert_eq!(err.location, None);
+    assert_eq!(err.render(), "Invalid digit\n");
+
+    // The malformed line is skipped, unless strict.
+    let parse =
+        |strict| filter_lines(input, strict, |ln| parse_span::<u32>(input, ln));
+    assert_eq!(parse(false).unwrap(), [12]);
+    assert!(parse(true).unwrap_err().is::<ParseError>());
+}
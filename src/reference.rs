@@ -0,0 +1,402 @@
+//! Slow but obviously correct solvers for the parts whose fast solutions
+//! rely on tricks, and a harness comparing both on generated inputs.
+
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::panic;
+
+use crate::gen::{self, Rng};
+use crate::{find_day, groups, Part};
+
+/// Reference solver of a part.
+pub struct Reference {
+    pub day: u32,
+    pub part: Part,
+    solve: fn(&str) -> Result<String>,
+}
+
+impl Reference {
+    /// Solves `input`, failing on inputs that have no single answer.
+    pub fn solve(&self, input: &str) -> Result<String> {
+        (self.solve)(input)
+    }
+
+    /// The answers of the reference and of the day, if they differ. Inputs
+    /// the reference can not solve never differ.
+    pub fn compare(&self, input: &str) -> Option<(String, String)> {
+        let expected = self.solve(input).ok()?;
+        let actual = panic::catch_unwind(|| {
+            find_day(self.day)
+                .with_context(|| format!("Day {} is not solved", self.day))
+                .and_then(|day| day.parse(input))
+                .and_then(|puzzle| puzzle.solve(self.part))
+        });
+        let actual = match actual {
+            Ok(Ok(answer)) => answer,
+            Ok(Err(e)) => format!("error: {:#}", e),
+            Err(_) => "panic".to_owned(),
+        };
+
+        if expected == actual {
+            None
+        } else {
+            Some((expected, actual))
+        }
+    }
+
+    /// Compares the answers on inputs generated from `seeds`, trying all
+    /// seeds of a size before the next size. Returns the first mismatch,
+    /// shrunk to as few lines as possible.
+    pub fn differential(
+        &self,
+        seeds: impl Iterator<Item = u64> + Clone,
+        sizes: &[usize],
+    ) -> Result<Option<Mismatch>> {
+        for &size in sizes {
+            for seed in seeds.clone() {
+                let input = gen::generate(self.day, &mut Rng::new(seed), size)?;
+
+                if self.compare(&input).is_some() {
+                    let input = self.shrink(&input);
+                    let (expected, actual) = self.compare(&input).unwrap();
+
+                    return Ok(Some(Mismatch {
+                        day: self.day,
+                        part: self.part,
+                        seed,
+                        size,
+                        input,
+                        expected,
+                        actual,
+                    }));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Removes lines of a mismatching `input` for as long as the answers
+    /// still differ.
+    fn shrink(&self, input: &str) -> String {
+        let mut lines: Vec<&str> = input.lines().collect();
+        let mut i = 0;
+
+        while i < lines.len() {
+            let mut shrunk = lines.clone();
+            shrunk.remove(i);
+
+            let candidate = join_lines(&shrunk);
+            if self.compare(&candidate).is_some() {
+                lines = shrunk;
+            } else {
+                i += 1;
+            }
+        }
+
+        join_lines(&lines)
+    }
+}
+
+fn join_lines(lines: &[&str]) -> String {
+    lines.iter().map(|ln| format!("{}\n", ln)).collect()
+}
+
+/// Input on which a day and its reference disagree.
+#[derive(Debug, Clone)]
+pub struct Mismatch {
+    pub day: u32,
+    pub part: Part,
+    /// Seed and size the input was generated from, before shrinking.
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {} part {} answered '{}' instead of '{}' (seed {}, size {}) \
+             on:\n{}",
+            self.day,
+            self.part,
+            self.actual,
+            self.expected,
+            self.seed,
+            self.size,
+            self.input
+        )
+    }
+}
+
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        day: 10,
+        part: Part::Two,
+        solve: day10_part2,
+    },
+    Reference {
+        day: 14,
+        part: Part::Two,
+        solve: day14_part2,
+    },
+    Reference {
+        day: 16,
+        part: Part::Two,
+        solve: day16_part2,
+    },
+];
+
+fn parse_numbers<T: std::str::FromStr>(input: &str, sep: char) -> Result<Vec<T>>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    input
+        .split(sep)
+        .map(|s| s.trim().parse().with_context(|| format!("Invalid '{}'", s)))
+        .collect()
+}
+
+/// Counts the adapter chains one by one. Adapters with the same joltage are
+/// still different adapters.
+fn day10_part2(input: &str) -> Result<String> {
+    fn count(joltages: &[u64], from: u64, device: u64) -> u64 {
+        match joltages.split_first() {
+            None => u64::from(device - from <= 3),
+            Some((&next, rest)) if next - from <= 3 => {
+                // Either use the next adapter, or skip it.
+                count(rest, next, device) + count(rest, from, device)
+            }
+            Some(_) => 0,
+        }
+    }
+
+    let mut joltages: Vec<u64> = parse_numbers(input.trim_end(), '\n')?;
+    joltages.sort_unstable();
+
+    // Every adapter doubles the chains to try, so keep this cheap.
+    if joltages.len() > 20 {
+        bail!("Too many adapters to count their chains one by one");
+    }
+
+    let device = joltages.last().context("No adapters")? + 3;
+
+    match count(&joltages, 0, device) {
+        0 => bail!("No chain of adapters"),
+        n => Ok(n.to_string()),
+    }
+}
+
+/// Writes to every address the mask expands to, one floating bit at a time.
+fn day14_part2(input: &str) -> Result<String> {
+    fn expand(addr: &[u8], mask: &[u8], out: &mut Vec<u64>) {
+        let mut addr = addr.to_vec();
+
+        for i in 0..36 {
+            match mask[i] {
+                b'1' => addr[i] = b'1',
+                b'X' => {
+                    for bit in [b'0', b'1'] {
+                        let mut mask = mask.to_vec();
+                        mask[i] = b'0';
+                        addr[i] = bit;
+                        expand(&addr, &mask, out);
+                    }
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        let addr = std::str::from_utf8(&addr).unwrap();
+        out.push(u64::from_str_radix(addr, 2).unwrap());
+    }
+
+    let mut mem = HashMap::new();
+    let mut mask = None;
+
+    for ln in input.lines() {
+        let (k, v) = ln.split_once(" = ").context("Expected 'KEY = VALUE'")?;
+
+        if k == "mask" {
+            if v.len() != 36 || v.bytes().any(|b| !b"01X".contains(&b)) {
+                bail!("Invalid mask '{}'", v);
+            }
+            mask = Some(v.as_bytes());
+        } else {
+            let addr: u64 = k
+                .strip_prefix("mem[")
+                .and_then(|k| k.strip_suffix(']'))
+                .context("Expected 'mem[ADDR]'")?
+                .parse()?;
+            let val: u64 = v.parse()?;
+            let mask = mask.context("Write before the first mask")?;
+
+            if addr >> 36 != 0 {
+                bail!("Address {} does not fit in 36 bits", addr);
+            }
+
+            let mut addrs = Vec::new();
+            let addr = format!("{:036b}", addr);
+            expand(addr.as_bytes(), mask, &mut addrs);
+
+            for addr in addrs {
+                mem.insert(addr, val);
+            }
+        }
+    }
+
+    Ok(mem.values().sum::<u64>().to_string())
+}
+
+/// Tries every assignment of fields to positions, and requires exactly one
+/// of them to fit all the valid tickets.
+fn day16_part2(input: &str) -> Result<String> {
+    // Assigns the field with the fewest free positions first, so that the
+    // search only branches where it has to.
+    fn assign(
+        candidates: &[Vec<usize>],
+        taken: &mut Vec<bool>,
+        assigned: &mut Vec<Option<usize>>,
+        found: &mut Vec<Vec<usize>>,
+    ) {
+        if found.len() > 1 {
+            return;
+        }
+
+        let free = |field: usize| {
+            candidates[field].iter().filter(|&&pos| !taken[pos]).count()
+        };
+        let field = (0..candidates.len())
+            .filter(|&field| assigned[field].is_none())
+            .min_by_key(|&field| free(field));
+
+        let Some(field) = field else {
+            found.push(assigned.iter().map(|pos| pos.unwrap()).collect());
+            return;
+        };
+
+        for &pos in &candidates[field] {
+            if !taken[pos] {
+                taken[pos] = true;
+                assigned[field] = Some(pos);
+                assign(candidates, taken, assigned, found);
+                assigned[field] = None;
+                taken[pos] = false;
+            }
+        }
+    }
+
+    let sections: Vec<_> = groups(input).collect();
+    let [rules, mine, nearby] = &sections[..] else {
+        bail!("Expected 3 sections");
+    };
+
+    let rules = rules
+        .iter()
+        .map(|ln| {
+            let (name, ranges) =
+                ln.split_once(": ").context("Expected 'NAME: RANGES'")?;
+            let ranges = ranges
+                .split(" or ")
+                .map(|r| {
+                    let (lo, hi) =
+                        r.split_once('-').context("Expected 'A-B'")?;
+                    Ok((lo.parse::<u32>()?, hi.parse::<u32>()?))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok((name, ranges))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let fits = |ranges: &[(u32, u32)], n: u32| {
+        ranges.iter().any(|&(lo, hi)| lo <= n && n <= hi)
+    };
+
+    let mine: Vec<u32> = match &mine[..] {
+        ["your ticket:", ticket] => parse_numbers(ticket, ',')?,
+        _ => bail!("Expected my ticket"),
+    };
+    let mut tickets = vec![mine.clone()];
+
+    for ln in nearby.iter().skip(1) {
+        let ticket: Vec<u32> = parse_numbers(ln, ',')?;
+
+        if ticket.len() != mine.len() {
+            bail!("Tickets of different lengths");
+        }
+
+        if ticket.iter().all(|&n| rules.iter().any(|(_, r)| fits(r, n))) {
+            tickets.push(ticket);
+        }
+    }
+
+    if rules.len() != mine.len() {
+        bail!("{} rules for {} fields", rules.len(), mine.len());
+    }
+
+    let candidates: Vec<Vec<usize>> = rules
+        .iter()
+        .map(|(_, ranges)| {
+            (0..mine.len())
+                .filter(|&pos| tickets.iter().all(|t| fits(ranges, t[pos])))
+                .collect()
+        })
+        .collect();
+
+    let mut found = Vec::new();
+    assign(
+        &candidates,
+        &mut vec![false; mine.len()],
+        &mut vec![None; rules.len()],
+        &mut found,
+    );
+
+    let positions = match &found[..] {
+        [positions] => positions,
+        [] => bail!("No assignment of fields fits the tickets"),
+        _ => bail!("Several assignments of fields fit the tickets"),
+    };
+
+    let product: u64 = rules
+        .iter()
+        .zip(positions)
+        .filter(|((name, _), _)| name.starts_with("departure"))
+        .map(|(_, &pos)| mine[pos] as u64)
+        .product();
+
+    Ok(product.to_string())
+}
+
+#[test]
+fn test_references() {
+    let day10 = "16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4\n";
+    assert_eq!(day10_part2(day10).unwrap(), "8");
+    // Either adapter of 1 jolt alone, or both of them.
+    assert_eq!(day10_part2("1\n1\n").unwrap(), "3");
+    let many: String = (1..=21).map(|n| format!("{}\n", n)).collect();
+    assert!(day10_part2(&many).is_err());
+
+    let day14 = "mask = 000000000000000000000000000000X1001X\n\
+                 mem[42] = 100\n\
+                 mask = 00000000000000000000000000000000X0XX\n\
+                 mem[26] = 1\n";
+    assert_eq!(day14_part2(day14).unwrap(), "208");
+
+    let day16 = "departure class: 0-1 or 4-19\n\
+                 row: 0-5 or 8-19\n\
+                 departure seat: 0-13 or 16-19\n\
+                 \n\
+                 your ticket:\n\
+                 11,12,13\n\
+                 \n\
+                 nearby tickets:\n\
+                 3,9,18\n\
+                 15,1,5\n\
+                 5,14,9\n";
+    assert_eq!(day16_part2(day16).unwrap(), (12 * 13).to_string());
+}
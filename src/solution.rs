@@ -0,0 +1,459 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::env;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::output::{self, Format, Record};
+use crate::*;
+
+/// A puzzle of a single day.
+///
+/// The input is parsed once from its text and then shared by both parts.
+pub trait Solution {
+    const DAY: u32;
+
+    type Input: Send + Sync;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parses `input` like `parse`, but fails on malformed lines instead of
+    /// skipping them if `strict`. Days that never skip lines keep the
+    /// default.
+    fn parse_with(input: &str, _strict: bool) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("Invalid part '{}'", s),
+        }
+    }
+}
+
+/// Parsed input of a day, with its answers rendered as strings.
+pub trait Puzzle: Send + Sync {
+    fn solve(&self, part: Part) -> Result<String>;
+}
+
+struct Parsed<S: Solution> {
+    input: S::Input,
+    _solution: PhantomData<fn() -> S>,
+}
+
+impl<S: Solution> Puzzle for Parsed<S> {
+    fn solve(&self, part: Part) -> Result<String> {
+        match part {
+            Part::One => S::part1(&self.input).map(|x| x.to_string()),
+            Part::Two => S::part2(&self.input).map(|x| x.to_string()),
+        }
+    }
+}
+
+/// Type-erased entry of `DAYS`, used for dispatching days at runtime.
+pub struct Day {
+    pub day: u32,
+    parse: fn(&str, bool) -> Result<Box<dyn Puzzle>>,
+}
+
+impl Day {
+    pub const fn new<S>() -> Day
+    where
+        S: Solution + 'static,
+        S::Input: 'static,
+    {
+        Day {
+            day: S::DAY,
+            parse: parse_boxed::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Puzzle>> {
+        (self.parse)(input, false)
+    }
+
+    /// Parses `input`, failing on malformed lines if `strict`.
+    pub fn parse_with(
+        &self,
+        input: &str,
+        strict: bool,
+    ) -> Result<Box<dyn Puzzle>> {
+        (self.parse)(input, strict)
+    }
+
+    /// Reads `input` and solves `parts` of it, timing each part.
+    pub fn run(
+        &self,
+        input: &InputSource,
+        parts: &[Part],
+        strict: bool,
+    ) -> Vec<Record> {
+        let puzzle = self.load(input, strict);
+
+        parts.iter().map(|&part| self.solve(&puzzle, part)).collect()
+    }
+
+    /// Reads and parses `input`. Parse errors point at the input file, and
+    /// a panicking parser is an error too.
+    pub(crate) fn load(
+        &self,
+        input: &InputSource,
+        strict: bool,
+    ) -> Result<Box<dyn Puzzle>> {
+        input
+            .read()
+            .and_then(|text| catch_panic(|| self.parse_with(&text, strict)))
+            .map_err(|e| with_file(e, input))
+    }
+
+    /// Solves `part` of a loaded `puzzle`, timing it and measuring its heap
+    /// usage if enabled. A panic only fails this part.
+    pub(crate) fn solve(
+        &self,
+        puzzle: &Result<Box<dyn Puzzle>>,
+        part: Part,
+    ) -> Record {
+        let puzzle = match puzzle {
+            Ok(puzzle) => puzzle,
+            Err(e) => {
+                // Keep parse errors intact, so that they can be rendered
+                // with the offending line.
+                let answer = match e.downcast_ref::<ParseError>() {
+                    Some(pe) => Err(pe.clone().into()),
+                    None => Err(anyhow!("{:#}", e)),
+                };
+
+                return Record {
+                    day: self.day,
+                    part,
+                    answer,
+                    duration: Duration::ZERO,
+                    memory: None,
+                };
+            }
+        };
+
+        let ((answer, duration), memory) = memory::measure(|| {
+            let now = Instant::now();
+            let answer = catch_panic(|| puzzle.solve(part));
+            (answer, now.elapsed())
+        });
+
+        Record {
+            day: self.day,
+            part,
+            answer,
+            duration,
+            memory,
+        }
+    }
+}
+
+/// Runs `f`, turning a panic into an error with its message.
+fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let msg = match payload.downcast_ref::<&str>() {
+            Some(msg) => msg.to_string(),
+            None => match payload.downcast_ref::<String>() {
+                Some(msg) => msg.clone(),
+                None => "unknown cause".to_owned(),
+            },
+        };
+
+        Err(anyhow!("Panicked: {}", msg))
+    })
+}
+
+/// Points a parse error at the file it was read from.
+fn with_file(e: anyhow::Error, input: &InputSource) -> anyhow::Error {
+    match (e.downcast_ref::<ParseError>(), input) {
+        (Some(pe), InputSource::Path(path)) => {
+            pe.clone().with_file(path).into()
+        }
+        _ => e,
+    }
+}
+
+fn parse_boxed<S>(input: &str, strict: bool) -> Result<Box<dyn Puzzle>>
+where
+    S: Solution + 'static,
+    S::Input: 'static,
+{
+    Ok(Box::new(Parsed::<S> {
+        input: S::parse_with(input, strict)?,
+        _solution: PhantomData,
+    }))
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+    Day::new::<day13::Day13>(),
+    Day::new::<day14::Day14>(),
+    Day::new::<day15::Day15>(),
+    Day::new::<day16::Day16>(),
+];
+
+pub fn find_day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// Solves both parts of `S` and prints them. Used by the per-day binaries.
+pub fn run<S>() -> Result<()>
+where
+    S: Solution + 'static,
+    S::Input: 'static,
+{
+    run_parts::<S>(&Part::BOTH)
+}
+
+/// Arguments of the per-day binaries: an optional input path (or `-` for
+/// stdin), `--format FORMAT`, `--strict`, and options and flags specific to
+/// the day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Args {
+    pub path: Option<String>,
+    pub format: Format,
+    pub strict: bool,
+    /// Day specific options and their values, in the order given.
+    pub options: Vec<(&'static str, String)>,
+    /// Day specific flags given, which take no value.
+    pub flags: Vec<&'static str>,
+}
+
+impl Args {
+    /// Parses `args`, accepting the day specific `options`, which take a
+    /// value, and `flags` besides the common ones.
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+        options: &[&'static str],
+        flags: &[&'static str],
+    ) -> Result<Args> {
+        let mut parsed = Args {
+            path: None,
+            format: Format::Text,
+            strict: false,
+            options: Vec::new(),
+            flags: Vec::new(),
+        };
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--format" => {
+                    let format = args.next().context("Missing format")?;
+                    parsed.format = format.parse()?;
+                }
+                "--strict" => parsed.strict = true,
+                _ if flags.contains(&arg.as_str()) => {
+                    let flag = flags.iter().find(|&&flag| flag == arg);
+                    parsed.flags.extend(flag);
+                }
+                _ => match options.iter().find(|&&opt| opt == arg) {
+                    Some(&opt) => {
+                        let value = args.next().with_context(|| {
+                            format!("Missing {} value", opt)
+                        })?;
+                        parsed.options.push((opt, value));
+                    }
+                    None if parsed.path.is_none()
+                        && (arg == "-" || !arg.starts_with('-')) =>
+                    {
+                        parsed.path = Some(arg)
+                    }
+                    None => bail!("{}", usage(options, flags)),
+                },
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Whether any day specific option or flag was given.
+    pub fn has_day_args(&self) -> bool {
+        !self.options.is_empty() || !self.flags.is_empty()
+    }
+
+    pub fn has(&self, flag: &str) -> bool {
+        self.flags.contains(&flag)
+    }
+
+    /// Values given to `option`, in order.
+    pub fn values<'a>(
+        &'a self,
+        option: &'a str,
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        self.options
+            .iter()
+            .filter(move |(opt, _)| *opt == option)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Last value given to `option`, parsed.
+    pub fn value<T>(&self, option: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        self.values(option)
+            .last()
+            .map(|value| value.parse())
+            .transpose()
+            .with_context(|| format!("Invalid {} value", option))
+    }
+
+    pub fn input(&self, day: u32) -> InputSource {
+        InputSource::resolve(day, self.path.as_deref())
+    }
+
+    /// Reads and parses the input of `S`.
+    pub fn load<S: Solution>(&self) -> Result<S::Input> {
+        let input = self.input(S::DAY);
+
+        input
+            .read()
+            .and_then(|text| S::parse_with(&text, self.strict))
+            .map_err(|e| with_file(e, &input))
+    }
+}
+
+fn usage(options: &[&str], flags: &[&str]) -> String {
+    let mut usage = "Usage: [--format text|json|csv] [--strict]".to_owned();
+
+    for opt in options {
+        usage += &format!(" [{} VALUE]", opt);
+    }
+
+    for flag in flags {
+        usage += &format!(" [{}]", flag);
+    }
+
+    usage + " [INPUT]"
+}
+
+/// Solves `parts` of `S` and prints them. The per-day binaries take the
+/// arguments of `Args`, without day specific options.
+pub fn run_parts<S>(parts: &[Part]) -> Result<()>
+where
+    S: Solution + 'static,
+    S::Input: 'static,
+{
+    run_args::<S>(&Args::parse(env::args().skip(1), &[], &[])?, parts)
+}
+
+/// Solves `parts` of `S` with the input and format of `args`, and prints
+/// them.
+pub fn run_args<S>(args: &Args, parts: &[Part]) -> Result<()>
+where
+    S: Solution + 'static,
+    S::Input: 'static,
+{
+    let format = args.format;
+    let records = Day::new::<S>().run(&args.input(S::DAY), parts, args.strict);
+
+    if format == Format::Text {
+        for rec in &records {
+            match &rec.answer {
+                Ok(answer) => println!("part{}: {}", rec.part, answer),
+                Err(e) => println!("part{}: error: {:#}", rec.part, e),
+            }
+        }
+
+        eprint!("{}", output::diagnostics(&records));
+    } else {
+        print!("{}", output::render(&records, format));
+    }
+
+    let failures = records.iter().filter(|rec| rec.answer.is_err()).count();
+
+    if failures > 0 {
+        bail!("{} parts failed", failures);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_args() {
+    let args = |args: &[&str]| {
+        let args = args.iter().map(|&arg| arg.to_owned());
+        Args::parse(args, &["--k", "--target"], &["--all"])
+    };
+
+    let parsed = args(&["--k", "3", "in.txt", "--strict", "--k", "4"]).unwrap();
+    assert_eq!(parsed.path.as_deref(), Some("in.txt"));
+    assert!(parsed.strict);
+    assert!(parsed.has_day_args());
+    assert!(!parsed.has("--all"));
+    assert_eq!(parsed.values("--k").collect::<Vec<_>>(), ["3", "4"]);
+    assert_eq!(parsed.value::<u32>("--k").unwrap(), Some(4));
+    assert_eq!(parsed.value::<u32>("--target").unwrap(), None);
+
+    let parsed = args(&["-", "--format", "csv"]).unwrap();
+    assert_eq!(parsed.input(1), InputSource::Stdin);
+    assert_eq!(parsed.format, Format::Csv);
+    assert!(!parsed.has_day_args());
+    assert!(args(&["--all"]).unwrap().has("--all"));
+
+    assert!(args(&["--k"]).is_err());
+    assert!(args(&["--bench"]).is_err());
+    assert!(args(&["a.txt", "b.txt"]).is_err());
+    assert!(args(&["--target", "x"])
+        .unwrap()
+        .value::<u64>("--target")
+        .is_err());
+}
+
+#[test]
+fn test_catch_panic() {
+    assert_eq!(catch_panic(|| Ok(1)).unwrap(), 1);
+    assert!(catch_panic(|| -> Result<()> { bail!("failed") }).is_err());
+
+    let err = catch_panic(|| -> Result<()> { panic!("on purpose") });
+    assert_eq!(err.unwrap_err().to_string(), "Panicked: on purpose");
+
+    let err = catch_panic(|| -> Result<()> { panic!("part {}", 2) });
+    assert_eq!(err.unwrap_err().to_string(), "Panicked: part 2");
+}
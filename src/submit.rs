@@ -0,0 +1,334 @@
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::Part;
+
+/// Outcome of submitting an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous answer.
+    Wait(Duration),
+    /// The part was already solved, or its first part was not.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    /// Parses the response page of the answer endpoint.
+    pub fn parse(html: &str) -> Verdict {
+        static RE_WAIT: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"You have (?:(\d+)m ?)?(?:(\d+)s )?left to wait")
+                .unwrap()
+        });
+
+        if html.contains("That's the right answer") {
+            Verdict::Correct
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if html.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if let Some(cap) = RE_WAIT.captures(html) {
+            let mins: u64 =
+                cap.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+            let secs: u64 =
+                cap.get(2).map_or(0, |m| m.as_str().parse().unwrap());
+            Verdict::Wait(Duration::from_secs(mins * 60 + secs))
+        } else if html.contains("You gave an answer too recently") {
+            Verdict::Wait(Duration::from_secs(60))
+        } else if html.contains("You don't seem to be solving the right level")
+        {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Whether the answer is known to be wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    /// How long the server makes us wait before the next answer.
+    pub fn cooldown(self) -> Option<Duration> {
+        match self {
+            Verdict::Wait(d) => Some(d),
+            // "Please wait one minute before trying again."
+            v if v.is_wrong() => Some(Duration::from_secs(60)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wait(d) => write!(f, "wait:{}", d.as_secs()),
+            Verdict::WrongLevel => write!(f, "wrong-level"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let verdict = match s {
+            "correct" => Verdict::Correct,
+            "wrong" => Verdict::Wrong,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "wrong-level" => Verdict::WrongLevel,
+            "unknown" => Verdict::Unknown,
+            _ => match s.strip_prefix("wait:") {
+                Some(secs) => Verdict::Wait(Duration::from_secs(
+                    secs.parse().context("Invalid wait")?,
+                )),
+                None => bail!("Invalid verdict '{}'", s),
+            },
+        };
+
+        Ok(verdict)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every submitted answer, stored as tab separated values so that it can
+/// be appended to.
+#[derive(Debug, Clone)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the history from `path`. A missing file has no attempts.
+    pub fn load(path: impl AsRef<Path>) -> Result<History> {
+        let path = path.as_ref().to_owned();
+        let data = match fs::read_to_string(&path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!("Failed to read '{}'", path.display())
+                })
+            }
+        };
+
+        let attempts = data
+            .lines()
+            .enumerate()
+            .filter(|(_, ln)| !ln.is_empty())
+            .map(|(i, ln)| {
+                parse_attempt(ln).with_context(|| {
+                    format!("Invalid '{}' at line {}", path.display(), i + 1)
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(History {
+            path,
+            attempts,
+        })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Fails if submitting `answer` is known to be pointless: the part is
+    /// already solved, the answer was already rejected or falls outside of
+    /// the too high/too low bounds, or the server's cooldown after the last
+    /// answer has not run out. Answers that can not be recorded, because
+    /// they contain a tab or a line break, are refused as well.
+    pub fn check(
+        &self,
+        day: u32,
+        part: Part,
+        answer: &str,
+        now: u64,
+    ) -> Result<()> {
+        check_recordable(answer)?;
+
+        if let Some(wait_until) = self
+            .attempts
+            .iter()
+            .filter_map(|a| Some(a.time + a.verdict.cooldown()?.as_secs()))
+            .max()
+        {
+            if wait_until > now {
+                bail!("Rate limited, wait {}s", wait_until - now);
+            }
+        }
+
+        let num = answer.parse::<i64>().ok();
+
+        for a in self.attempts.iter().filter(|a| a.day == day && a.part == part)
+        {
+            let a_num = a.answer.parse::<i64>().ok();
+            let higher = matches!((num, a_num), (Some(n), Some(h)) if n >= h);
+            let lower = matches!((num, a_num), (Some(n), Some(l)) if n <= l);
+
+            match a.verdict {
+                Verdict::Correct if a.answer == answer => {
+                    bail!("'{}' was already accepted", answer)
+                }
+                Verdict::Correct => {
+                    bail!("Already solved with '{}'", a.answer)
+                }
+                v if v.is_wrong() && a.answer == answer => {
+                    bail!("'{}' was already rejected as {}", answer, v)
+                }
+                Verdict::TooHigh if higher => {
+                    bail!(
+                        "'{}' is too high, '{}' already was",
+                        answer,
+                        a.answer
+                    )
+                }
+                Verdict::TooLow if lower => {
+                    bail!("'{}' is too low, '{}' already was", answer, a.answer)
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records `attempt` and appends it to the history file.
+    pub fn push(&mut self, attempt: Attempt) -> Result<()> {
+        check_recordable(&attempt.answer)?;
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).with_context(|| {
+                format!("Failed to create '{}'", dir.display())
+            })?;
+        }
+
+        let mut f = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| {
+                format!("Failed to open '{}'", self.path.display())
+            })?;
+
+        writeln!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            attempt.time,
+            attempt.day,
+            attempt.part,
+            attempt.verdict,
+            attempt.answer
+        )
+        .with_context(|| {
+            format!("Failed to write '{}'", self.path.display())
+        })?;
+
+        self.attempts.push(attempt);
+
+        Ok(())
+    }
+}
+
+/// Fails if `answer` would not survive a round trip through the history.
+fn check_recordable(answer: &str) -> Result<()> {
+    if answer.contains(&['\t', '\n', '\r'][..]) {
+        bail!("'{}' contains a tab or a line break", answer.escape_debug());
+    }
+
+    Ok(())
+}
+
+fn parse_attempt(s: &str) -> Result<Attempt> {
+    let mut split = s.splitn(5, '\t');
+    let mut next = || split.next().context("Missing field");
+
+    Ok(Attempt {
+        time: next()?.parse().context("Invalid time")?,
+        day: next()?.parse().context("Invalid day")?,
+        part: next()?.parse()?,
+        verdict: next()?.parse()?,
+        answer: next()?.to_owned(),
+    })
+}
+
+pub fn unix_time() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+#[test]
+fn test_parse_verdict() {
+    let parse = Verdict::parse;
+
+    assert_eq!(
+        parse("<p>That's the right answer!  You are one gold star closer</p>"),
+        Verdict::Correct
+    );
+    assert_eq!(
+        parse(
+            "<p>That's not the right answer; your answer is too high.  If \
+             you're stuck, ...  Please wait one minute before trying again.</p>"
+        ),
+        Verdict::TooHigh
+    );
+    assert_eq!(
+        parse("That's not the right answer; your answer is too low."),
+        Verdict::TooLow
+    );
+    assert_eq!(parse("That's not the right answer."), Verdict::Wrong);
+    assert_eq!(
+        parse(
+            "You gave an answer too recently; you have to wait after \
+             submitting an answer before trying again.  You have 37s left \
+             to wait."
+        ),
+        Verdict::Wait(Duration::from_secs(37))
+    );
+    assert_eq!(
+        parse("You have 4m 2s left to wait."),
+        Verdict::Wait(Duration::from_secs(242))
+    );
+    assert_eq!(
+        parse(
+            "You don't seem to be solving the right level.  Did you already \
+             complete it?"
+        ),
+        Verdict::WrongLevel
+    );
+    assert_eq!(parse("<html>Something else</html>"), Verdict::Unknown);
+
+    for v in &[Verdict::TooLow, Verdict::Wait(Duration::from_secs(5))] {
+        assert_eq!(v.to_string().parse::<Verdict>().unwrap(), *v);
+    }
+}
@@ -0,0 +1,89 @@
+//! Step by step rendering of the simulation days, to watch them run.
+
+use anyhow::{bail, Context, Result};
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use crate::{day11, day12, Part, Solution};
+
+/// Days that can be visualized.
+pub const DAYS: [u32; 2] = [11, 12];
+
+/// Destination of the rendered frames.
+pub struct Visualizer {
+    out: Box<dyn Write>,
+    /// Delay after each frame, when animating in the terminal.
+    delay: Option<Duration>,
+    frames: usize,
+}
+
+impl Visualizer {
+    /// Animates the frames in the terminal, redrawing the screen for each
+    /// one and pausing `delay` in between.
+    pub fn terminal(delay: Duration) -> Visualizer {
+        Visualizer {
+            out: Box::new(io::stdout()),
+            delay: Some(delay),
+            frames: 0,
+        }
+    }
+
+    /// Writes the frames one after the other to `path`.
+    pub fn file(path: impl AsRef<Path>) -> Result<Visualizer> {
+        let path = path.as_ref();
+        let f = File::create(path).with_context(|| {
+            format!("Failed to create '{}'", path.display())
+        })?;
+
+        Ok(Visualizer {
+            out: Box::new(BufWriter::new(f)),
+            delay: None,
+            frames: 0,
+        })
+    }
+
+    /// Renders a frame made of a `title` line followed by `body`.
+    pub fn frame(&mut self, title: &str, body: &dyn Display) -> Result<()> {
+        match self.delay {
+            Some(delay) => {
+                // Clear the screen and move to its top left corner.
+                write!(self.out, "\x1b[2J\x1b[H{}\n{}", title, body)?;
+                self.out.flush()?;
+                thread::sleep(delay);
+            }
+            None => write!(self.out, "{}\n{}\n", title, body)?,
+        }
+
+        self.frames += 1;
+
+        Ok(())
+    }
+
+    /// Number of frames rendered so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn finish(mut self) -> Result<()> {
+        self.out.flush()?;
+        Ok(())
+    }
+}
+
+/// Parses `input` and renders every step of solving `part` of `day`.
+pub fn visualize(
+    day: u32,
+    input: &str,
+    part: Part,
+    vis: &mut Visualizer,
+) -> Result<()> {
+    match day {
+        11 => day11::visualize(&day11::Day11::parse(input)?, part, vis),
+        12 => day12::visualize(&day12::Day12::parse(input)?, part, vis),
+        _ => bail!("Day {} has no visualization", day),
+    }
+}
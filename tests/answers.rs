@@ -0,0 +1,32 @@
+use aoc_2020::{input_dir, Answers, InputSource, Part, DAYS};
+
+/// Solves every day against its input and compares it with the answers
+/// recorded in `data/answers.toml`. Days without an input or recorded
+/// answers, such as a day just started, are skipped.
+#[test]
+fn test_recorded_answers() {
+    let answers = Answers::load(input_dir().join("answers.toml")).unwrap();
+    assert!(!answers.is_empty(), "No recorded answers");
+
+    for day in DAYS {
+        let expected: Vec<_> = Part::BOTH
+            .iter()
+            .filter_map(|&part| Some((part, answers.get(day.day, part)?)))
+            .collect();
+
+        if expected.is_empty() {
+            continue;
+        }
+
+        let input = match InputSource::resolve(day.day, None) {
+            InputSource::Path(path) if !path.exists() => continue,
+            input => input.read().unwrap(),
+        };
+        let puzzle = day.parse(&input).unwrap();
+
+        for (part, expected) in expected {
+            let answer = puzzle.solve(part).unwrap();
+            assert_eq!(answer, expected, "day {} part {}", day.day, part);
+        }
+    }
+}
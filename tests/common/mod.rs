@@ -0,0 +1,138 @@
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+/// Stand-in HTTP server answering each connection with the next canned
+/// response, and recording the requests it received.
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+impl MockServer {
+    pub fn start(responses: Vec<(u16, &str)>) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let responses: Vec<_> = responses
+            .into_iter()
+            .map(|(code, body)| (code, body.to_owned()))
+            .collect();
+
+        let reqs = requests.clone();
+        let handle = thread::spawn(move || {
+            for (code, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                reqs.lock().unwrap().push(read_request(&mut reader));
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Mock\r\nContent-Type: text/plain\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    code,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        MockServer {
+            url,
+            requests,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        // Only wait for the server if all responses were consumed, an
+        // assertion may have failed before that.
+        if let Some(handle) = self.handle.take() {
+            if handle.is_finished() {
+                handle.join().unwrap();
+            }
+        }
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> Request {
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+
+    let mut split = line.split_whitespace();
+    let method = split.next().unwrap().to_owned();
+    let path = split.next().unwrap().to_owned();
+    let mut headers = Vec::new();
+
+    loop {
+        line.clear();
+        reader.read_line(&mut line).unwrap();
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+
+        let (k, v) = line.split_once(':').unwrap();
+        headers.push((k.trim().to_owned(), v.trim().to_owned()));
+    }
+
+    let len = headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .map_or(0, |(_, v)| v.parse().unwrap());
+    let mut body = vec![0; len];
+    reader.read_exact(&mut body).unwrap();
+
+    Request {
+        method,
+        path,
+        headers,
+        body: String::from_utf8(body).unwrap(),
+    }
+}
+
+/// Creates an empty directory, unique to `name`, under the temp directory.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "aoc-2020-{}-{}",
+        name,
+        std::process::id()
+    ));
+
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    dir
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use aoc_2020::client::{Client, Fetched};
+use common::{temp_dir, MockServer};
+use std::fs;
+
+#[test]
+fn test_fetch_and_cache() {
+    let server = MockServer::start(vec![(200, "1721\n979\n")]);
+    let client = Client::new(&server.url, "secret");
+    let dir = temp_dir("fetch");
+    let path = dir.join("input01.txt");
+
+    assert_eq!(
+        client.fetch_to_dir(1, &dir).unwrap(),
+        Fetched::Downloaded(path.clone())
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1721\n979\n");
+
+    // The server only answers once, so this must not hit the network.
+    assert_eq!(client.fetch_to_dir(1, &dir).unwrap(), Fetched::Cached(path));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2020/day/1/input");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_fetch_error() {
+    let server = MockServer::start(vec![(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+    )]);
+    let client = Client::new(&server.url, "expired");
+    let dir = temp_dir("fetch-error");
+
+    let err = client.fetch_to_dir(2, &dir).unwrap_err();
+    assert!(format!("{:#}", err).contains("400 Puzzle inputs differ"));
+    assert!(!dir.join("input02.txt").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,43 @@
+use aoc_2020::gen::{self, Rng};
+use aoc_2020::{find_day, Part};
+
+/// Parts that can not solve generated inputs: day 13 part 2 is not solved
+/// and day 15 part 2 takes too long for a test.
+const SKIPPED: [(u32, Part); 2] = [(13, Part::Two), (15, Part::Two)];
+
+/// Day, seed and size of inputs that once broke a solver.
+const REGRESSIONS: [(u32, u64, usize); 1] = [(9, 543, 100)];
+
+fn check(day: u32, seed: u64, size: usize) {
+    let solution = find_day(day).unwrap();
+    let input = gen::generate(day, &mut Rng::new(seed), size).unwrap();
+    let puzzle = solution.parse(&input).unwrap_or_else(|e| {
+        panic!("day {} seed {}: {:#}\n{}", day, seed, e, input)
+    });
+
+    for &part in &Part::BOTH {
+        if SKIPPED.contains(&(day, part)) {
+            continue;
+        }
+
+        if let Err(e) = puzzle.solve(part) {
+            panic!(
+                "day {} part {} seed {}: {:#}\n{}",
+                day, part, seed, e, input
+            );
+        }
+    }
+}
+
+#[test]
+fn test_generated_inputs_solve() {
+    for &day in &gen::DAYS {
+        for seed in 0..20 {
+            check(day, seed, [1, 5, 30, 100][seed as usize % 4]);
+        }
+    }
+
+    for &(day, seed, size) in &REGRESSIONS {
+        check(day, seed, size);
+    }
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::temp_dir;
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn test_memory() {
+    let dir = temp_dir("memory");
+    let path = dir.join("input14.txt");
+
+    fs::write(
+        &path,
+        "mask = 000000000000000000000000000000X1001X\n\
+         mem[42] = 100\n\
+         mask = 00000000000000000000000000000000X0XX\n\
+         mem[26] = 1\n",
+    )
+    .unwrap();
+
+    let out = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["14", "2", "--memory", "--format", "csv", "-i"])
+        .arg(&path)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let mut lines = stdout.lines();
+
+    assert_eq!(
+        lines.next().unwrap(),
+        "day,part,answer,duration_ns,peak_bytes,allocations,allocated_bytes,\
+         error"
+    );
+
+    let fields: Vec<_> = lines.next().unwrap().split(',').collect();
+    assert_eq!(fields[..3], ["14", "2", "208"]);
+
+    let peak: usize = fields[4].parse().unwrap();
+    let allocations: usize = fields[5].parse().unwrap();
+    let allocated: usize = fields[6].parse().unwrap();
+    assert!(allocations > 0);
+    assert!(0 < peak && peak <= allocated);
+}
@@ -0,0 +1,22 @@
+use std::process::Command;
+
+#[test]
+fn test_conflicting_modes() {
+    for args in [
+        ["--record", "--check"],
+        ["--check", "--record"],
+        ["--check", "--visualize"],
+        ["--record", "--record"],
+    ] {
+        let out = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["11"])
+            .args(args)
+            .output()
+            .unwrap();
+
+        assert!(!out.status.success());
+        assert!(String::from_utf8_lossy(&out.stderr).contains(
+            "Only one of --check, --record, --bench and --visualize can be used"
+        ));
+    }
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::temp_dir;
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn test_new_day() {
+    let root = temp_dir("new-day");
+    fs::create_dir_all(root.join("src/bin")).unwrap();
+    fs::write(
+        root.join("src/lib.rs"),
+        "pub mod bench;\n\npub mod day01;\npub mod day03;\n\npub use x;\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("src/solution.rs"),
+        "pub const DAYS: &[Day] = &[\n    \
+         Day::new::<day01::Day01>(),\n    \
+         Day::new::<day03::Day03>(),\n];\n",
+    )
+    .unwrap();
+
+    let new_day = |day: &str| {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["new-day", day, "--root"])
+            .arg(&root)
+            .output()
+            .unwrap()
+    };
+
+    assert!(new_day("2").status.success());
+    assert!(new_day("4").status.success());
+
+    assert_eq!(
+        fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+        "pub mod bench;\n\npub mod day01;\npub mod day02;\npub mod day03;\n\
+         pub mod day04;\n\npub use x;\n"
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("src/solution.rs")).unwrap(),
+        "pub const DAYS: &[Day] = &[\n    \
+         Day::new::<day01::Day01>(),\n    \
+         Day::new::<day02::Day02>(),\n    \
+         Day::new::<day03::Day03>(),\n    \
+         Day::new::<day04::Day04>(),\n];\n"
+    );
+
+    let module = fs::read_to_string(root.join("src/day02.rs")).unwrap();
+    assert!(module.contains("impl Solution for Day02 {"));
+    assert!(module.contains("const DAY: u32 = 2;"));
+    assert!(module.contains("fn test_example() {"));
+
+    let binary = fs::read_to_string(root.join("src/bin/day02.rs")).unwrap();
+    assert!(binary.contains("aoc_2020::run::<Day02>()"));
+
+    // Existing days are left alone.
+    fs::write(root.join("src/day02.rs"), "edited").unwrap();
+
+    let out = new_day("2");
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("already exists"));
+    assert_eq!(
+        fs::read_to_string(root.join("src/day02.rs")).unwrap(),
+        "edited"
+    );
+
+    // As are registered days without a module.
+    let out = new_day("3");
+    assert!(!out.status.success());
+    assert!(!root.join("src/day03.rs").exists());
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use anyhow::Result;
+use aoc_2020::gen::{self, Rng};
+use aoc_2020::parallel::run_parallel;
+use aoc_2020::{find_day, Day, InputSource, Part, Solution};
+use common::temp_dir;
+use std::fs;
+
+/// A day whose first part panics on purpose.
+struct Panics;
+
+impl Solution for Panics {
+    const DAY: u32 = 25;
+
+    type Input = ();
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(_input: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn part1(_input: &()) -> Result<u32> {
+        panic!("on purpose");
+    }
+
+    fn part2(_input: &()) -> Result<u32> {
+        Ok(2)
+    }
+}
+
+static PANICS: Day = Day::new::<Panics>();
+
+#[test]
+fn test_run_parallel() {
+    let dir = temp_dir("parallel");
+    let days: Vec<_> = [1, 6, 8, 10, 16]
+        .iter()
+        .map(|&day| {
+            let path = dir.join(format!("input{:02}.txt", day));
+            let input = gen::generate(day, &mut Rng::new(7), 30).unwrap();
+            fs::write(&path, input).unwrap();
+            (find_day(day).unwrap(), InputSource::Path(path))
+        })
+        .collect();
+
+    // A missing input fails both of its parts, and a panic fails only its
+    // own part, without failing the others.
+    let empty = dir.join("empty.txt");
+    fs::write(&empty, "").unwrap();
+
+    let mut with_missing = days.clone();
+    with_missing.insert(2, (find_day(2).unwrap(), InputSource::Path(dir)));
+    with_missing.push((&PANICS, InputSource::Path(empty)));
+
+    let expected: Vec<_> = with_missing
+        .iter()
+        .flat_map(|(day, input)| day.run(input, &Part::BOTH, false))
+        .map(|rec| (rec.day, rec.part, rec.answer.map_err(|e| e.to_string())))
+        .collect();
+
+    for jobs in [1, 3, 16] {
+        let actual: Vec<_> =
+            run_parallel(&with_missing, &Part::BOTH, jobs, false)
+                .into_iter()
+                .map(|rec| {
+                    (rec.day, rec.part, rec.answer.map_err(|e| e.to_string()))
+                })
+                .collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    assert_eq!(expected.iter().filter(|rec| rec.2.is_err()).count(), 3);
+
+    let panicked = &expected[expected.len() - 2..];
+    assert_eq!(
+        panicked[0],
+        (25, Part::One, Err("Panicked: on purpose".to_owned()))
+    );
+    assert_eq!(panicked[1], (25, Part::Two, Ok("2".to_owned())));
+}
@@ -0,0 +1,12 @@
+use aoc_2020::reference::REFERENCES;
+
+#[test]
+fn test_references_agree() {
+    for reference in REFERENCES {
+        if let Some(mismatch) =
+            reference.differential(0..20, &[1, 2, 5, 10, 30]).unwrap()
+        {
+            panic!("{}", mismatch);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use aoc_2020::find_day;
+use common::temp_dir;
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn test_strict() {
+    let dir = temp_dir("strict");
+    let path = dir.join("input07.txt");
+
+    fs::write(
+        &path,
+        "light red bags contain 1 shiny gold bag.\n\
+         dark orange bag holds 3 bright white bags.\n\
+         shiny gold bags contain no other bags.\n",
+    )
+    .unwrap();
+
+    let run = |strict: bool| {
+        let mut cmd = Command::new(env!("CARGO_BIN_EXE_aoc"));
+        cmd.args(["7", "1", "--format", "csv", "-i"]).arg(&path);
+
+        if strict {
+            cmd.arg("--strict");
+        }
+
+        cmd.output().unwrap()
+    };
+
+    // The malformed line is skipped by default...
+    let out = run(false);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.lines().nth(1).unwrap().starts_with("7,1,1,"));
+
+    // ...and pointed out in strict mode.
+    let out = run(true);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains(&format!(
+        "Expected '<color> bags contain <bags>' at line 2, column 1 of '{}'",
+        path.display()
+    )));
+    let line = "dark orange bag holds 3 bright white bags.";
+    assert!(stderr.contains(&format!(
+        "2 | {}\n  | {}\n",
+        line,
+        "^".repeat(line.len())
+    )));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_strict_per_parse() {
+    let day = find_day(7).unwrap();
+    let input = "light red bags contain 1 shiny gold bag.\n\
+                 dark orange bag holds 3 bright white bags.\n";
+
+    // Parsing strictly does not affect the next parses.
+    assert!(day.parse_with(input, true).is_err());
+    assert!(day.parse(input).is_ok());
+    assert!(day.parse_with(input, true).is_err());
+    assert!(day.parse_with(input, false).is_ok());
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use aoc_2020::client::Client;
+use aoc_2020::submit::{Attempt, History, Verdict};
+use aoc_2020::Part;
+use common::{temp_dir, MockServer};
+use std::fs;
+use std::process::Command;
+use std::time::Duration;
+
+const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer \
+                        is too high.  Please wait one minute before trying \
+                        again.</p></article>";
+const WAIT: &str = "<article><p>You gave an answer too recently; you have to \
+                    wait after submitting an answer before trying again.  You \
+                    have 42s left to wait.</p></article>";
+const RIGHT: &str = "<article><p>That's the right answer!  You are one gold \
+                     star closer to saving your vacation.</p></article>";
+
+#[test]
+fn test_submit() {
+    let server =
+        MockServer::start(vec![(200, TOO_HIGH), (200, WAIT), (200, RIGHT)]);
+    let client = Client::new(&server.url, "secret");
+
+    assert_eq!(client.submit(3, Part::Two, "900").unwrap(), Verdict::TooHigh);
+    assert_eq!(
+        client.submit(3, Part::Two, "800").unwrap(),
+        Verdict::Wait(Duration::from_secs(42))
+    );
+    assert_eq!(client.submit(3, Part::Two, "800").unwrap(), Verdict::Correct);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2020/day/3/answer");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    assert_eq!(
+        requests[0].header("Content-Type"),
+        Some("application/x-www-form-urlencoded")
+    );
+    assert_eq!(requests[0].body, "level=2&answer=900");
+}
+
+#[test]
+fn test_history() {
+    let dir = temp_dir("submit-history");
+    let path = dir.join("submissions.tsv");
+    let mut history = History::load(&path).unwrap();
+
+    assert!(history.check(3, Part::Two, "900", 1000).is_ok());
+
+    let attempt = |answer: &str, time, verdict| Attempt {
+        time,
+        day: 3,
+        part: Part::Two,
+        answer: answer.to_owned(),
+        verdict,
+    };
+    history.push(attempt("900", 1000, Verdict::TooHigh)).unwrap();
+    history.push(attempt("100", 1060, Verdict::TooLow)).unwrap();
+    history
+        .push(attempt("800", 1070, Verdict::Wait(Duration::from_secs(30))))
+        .unwrap();
+
+    // Reload to make sure everything survives the round trip.
+    let history = History::load(&path).unwrap();
+    assert_eq!(history.attempts().len(), 3);
+
+    assert!(history.check(3, Part::Two, "800", 1080).is_err());
+    // The wait is over, but not the minute after the too-low answer.
+    let err = history.check(3, Part::Two, "800", 1100).unwrap_err();
+    assert_eq!(err.to_string(), "Rate limited, wait 20s");
+    assert!(history.check(3, Part::Two, "800", 1120).is_ok());
+    assert!(history.check(3, Part::Two, "900", 1120).is_err());
+    assert!(history.check(3, Part::Two, "901", 1120).is_err());
+    assert!(history.check(3, Part::Two, "50", 1120).is_err());
+    assert!(history.check(3, Part::One, "900", 1120).is_ok());
+
+    // Tabs and line breaks would corrupt the history file.
+    assert!(history.check(3, Part::One, "9\t1", 1120).is_err());
+    assert!(history.check(3, Part::One, "91\n", 1120).is_err());
+
+    let mut history = history;
+    assert!(history.push(attempt("9\t1", 1120, Verdict::Wrong)).is_err());
+    assert_eq!(History::load(&path).unwrap().attempts().len(), 3);
+    history.push(attempt("800", 1120, Verdict::Correct)).unwrap();
+    assert!(history.check(3, Part::Two, "700", 1200).is_err());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_submit_unsolved_day() {
+    let dir = temp_dir("submit-unsolved");
+    let server = MockServer::start(vec![(200, RIGHT)]);
+
+    let submit = |day: &str| {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["submit", day, "1", "123", "--session", "secret"])
+            .args(["--base-url", &server.url, "--history"])
+            .arg(dir.join("submissions.tsv"))
+            .output()
+            .unwrap()
+    };
+
+    // A given answer is submitted even without a solver for its day.
+    let out = submit("20");
+    assert!(out.status.success());
+    assert_eq!(server.requests()[0].path, "/2020/day/20/answer");
+
+    let out = submit("26");
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr)
+        .contains("Day 26 is not between 1 and 25"));
+    assert_eq!(server.requests().len(), 1);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use common::temp_dir;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn visualize(day: &str, part: &str, input: &Path, frames: &Path) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([day, part, "--visualize", "--frames"])
+        .arg(frames)
+        .arg("-i")
+        .arg(input)
+        .output()
+        .unwrap();
+
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+    fs::read_to_string(frames).unwrap()
+}
+
+#[test]
+fn test_visualize() {
+    let dir = temp_dir("visualize");
+    let input = dir.join("input11.txt");
+    let frames = dir.join("frames.txt");
+
+    fs::write(&input, "L.L\nLLL\n").unwrap();
+
+    // The initial layout, then every round until nothing changes.
+    let rendered = visualize("11", "1", &input, &frames);
+    assert_eq!(
+        rendered,
+        "Day 11 part 1, round 0: 0 occupied seats\nL.L\nLLL\n\n\
+         Day 11 part 1, round 1: 5 occupied seats\n#.#\n###\n\n\
+         Day 11 part 1, round 2: 4 occupied seats\n#.#\n#L#\n\n"
+    );
+
+    let input = dir.join("input12.txt");
+    fs::write(&input, "F10\nN3\nF7\nR90\nF11\n").unwrap();
+
+    let rendered = visualize("12", "2", &input, &frames);
+    let titles: Vec<_> =
+        rendered.lines().filter(|ln| ln.starts_with("Day")).collect();
+    assert_eq!(titles.len(), 6);
+    assert_eq!(
+        titles[5],
+        "Day 12 part 2, action 5/5: east 214, north -72, distance 286"
+    );
+
+    // The last frame has the ship, its start, its waypoint and its route.
+    let last = rendered.rsplit("Day 12").next().unwrap();
+    for c in ['@', 'o', '+', '.'] {
+        assert!(last.contains(c), "missing '{}'", c);
+    }
+}